@@ -6,29 +6,204 @@ use crossterm::{
 };
 use std::io::{self, Write};
 use std::time::{Duration, Instant};
-use wv_core::fighter::{FighterData, FighterId};
-use wv_core::game::{GamePhase, GameState};
+use wv_core::config::{DepthMode, MatchConfig};
+use wv_core::fighter::{AttackData, FighterData, FighterId, FrameRange};
+use wv_core::game::{GamePhase, GameState, RoundEndReason, METER_BARS, METER_PER_BAR};
 use wv_core::input::InputState;
-use wv_core::physics::{ARENA_MIN_X, ARENA_MAX_X};
+use wv_core::physics::{ARENA_MAX_X, ARENA_MIN_X};
+use wv_core::rules::{RulesPreset, UnknownRulesPreset};
 use wv_core::state_machine::FighterState;
+use wv_core::weapon::WeaponData;
 
 const TARGET_FPS: u64 = 60;
 const FRAME_DURATION: Duration = Duration::from_nanos(1_000_000_000 / TARGET_FPS);
 
 fn main() -> io::Result<()> {
+    if std::env::args().any(|arg| arg == "--frame-data") {
+        return print_frame_data();
+    }
+
+    // Match settings every new game starts from
+    let template = GameState::new_in_select(match_config()?)
+        .map_err(|err| invalid_input(format!("invalid match settings: {}", err)))?;
+
     terminal::enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
 
-    let result = run_game(&mut stdout);
+    let result = run_game(&mut stdout, &template);
 
     execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
     terminal::disable_raw_mode()?;
     result
 }
 
-fn run_game(stdout: &mut io::Stdout) -> io::Result<()> {
-    let mut game = GameState::new_in_select();
+/// Print every fighter's frame data, including invincibility and armor, then exit.
+fn print_frame_data() -> io::Result<()> {
+    let mut stdout = io::stdout();
+    for id in FighterId::ALL {
+        let data = FighterData::get(id);
+        let moves = &data.moveset;
+        let weapon_damage = WeaponData::get(data.default_weapon).damage_type;
+        let mut attacks: Vec<(String, &AttackData)> = [
+            &moves.light_attack,
+            &moves.heavy_attack,
+            &moves.special_attack,
+            &moves.mid_kick,
+            &moves.low_kick,
+            &moves.aerial,
+            &moves.combo_finisher,
+            &moves.super_attack,
+        ]
+        .into_iter()
+        .map(|attack| (attack.name.to_string(), attack))
+        .collect();
+        for command in data.command_normals {
+            let label = format!(
+                "{} ({}+{:?})",
+                command.attack.name, command.direction, command.button
+            );
+            attacks.push((label, &command.attack));
+        }
+        for stance in data.stances {
+            for m in stance.moves {
+                attacks.push((format!("{} ({})", m.attack.name, stance.name), &m.attack));
+            }
+        }
+        writeln!(stdout, "{}", id)?;
+        for (label, dash) in [
+            ("Dash", &data.dash),
+            ("Backdash", &data.backdash),
+            ("Sidestep", &data.sidestep),
+        ] {
+            writeln!(
+                stdout,
+                "  {}: {}f, invuln {}, {} stamina",
+                label,
+                dash.frames,
+                frame_range_str(dash.invuln),
+                dash.stamina_cost,
+            )?;
+        }
+        writeln!(
+            stdout,
+            "  {:<34} {:>3} {:>3} {:>3} {:>5} {:>4} {:>4}  {:<10} {:<5} {:<6} {:<5} {:<7} {:<7} {:<7} Armor",
+            "Move", "SU", "ACT", "REC", "Total", "Hits", "Stam", "Class", "KD", "Type", "Track", "Invuln", "Proj", "Throw",
+        )?;
+        for (label, attack) in attacks {
+            let armor = attack.armor.map_or("-".to_string(), |a| {
+                format!("{} ({} hit)", a.frames, a.hits)
+            });
+            writeln!(
+                stdout,
+                "  {:<34} {:>3} {:>3} {:>3} {:>5} {:>4} {:>4}  {:<10} {:<5} {:<6} {:<5} {:<7} {:<7} {:<7} {}",
+                label,
+                attack.startup_frames,
+                attack.active_frames,
+                attack.recovery_frames,
+                attack.total_frames(),
+                attack.hits,
+                attack.stamina_cost,
+                attack.class.to_string(),
+                attack.knockdown.map_or("-".to_string(), |k| k.to_string()),
+                attack.damage_type.unwrap_or(weapon_damage).to_string(),
+                attack.tracking.to_string(),
+                frame_range_str(attack.invuln),
+                frame_range_str(attack.projectile_invuln),
+                frame_range_str(attack.throw_invuln),
+                armor,
+            )?;
+        }
+        writeln!(stdout)?;
+    }
+    Ok(())
+}
+
+fn frame_range_str(range: Option<FrameRange>) -> String {
+    range.map_or("-".to_string(), |r| r.to_string())
+}
+
+/// The value following `flag` on the command line, e.g. `--rules arcade`.
+fn flag_value(flag: &str) -> Option<String> {
+    let mut args = std::env::args().skip_while(|arg| arg != flag);
+    args.next()?;
+    args.next()
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Parse a flag's value, or `None` if the flag wasn't given.
+fn parse_flag<T: std::str::FromStr>(flag: &str) -> io::Result<Option<T>> {
+    flag_value(flag)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| invalid_input(format!("invalid value '{}' for {}", value, flag)))
+        })
+        .transpose()
+}
+
+/// Parse a percentage flag (e.g. `--start-health 50`) as a fraction.
+fn parse_pct_flag(flag: &str) -> io::Result<Option<f32>> {
+    Ok(parse_flag::<f32>(flag)?.map(|pct| pct / 100.0))
+}
+
+/// Build the match settings from command-line flags:
+///   --rounds <n>            best of n rounds (odd)
+///   --round-time <s|inf>    seconds per round, or inf for no time limit
+///   --countdown <frames>    pre-round countdown length
+///   --round-pause <frames>  pause after a round ends
+///   --start-health <pct>    --start-stamina <pct>
+///   --p1-health <pct>       --p2-health <pct>   handicap: share of starting health
+///   --p1-damage <pct>       --p2-damage <pct>   handicap: damage dealt
+///   --rules <preset>        combat rules (Standard, Tournament, Arcade)
+///   --free-depth            fighters keep their own Z; only tracking attacks follow sidesteps
+fn match_config() -> io::Result<MatchConfig> {
+    let mut config = MatchConfig::default();
+    if let Some(rounds) = parse_flag("--rounds")? {
+        config.rounds = rounds;
+    }
+    if flag_value("--round-time").is_some_and(|time| time.eq_ignore_ascii_case("inf")) {
+        config.round_time = None;
+    } else if let Some(seconds) = parse_flag("--round-time")? {
+        config.round_time = Some(seconds);
+    }
+    if let Some(frames) = parse_flag("--countdown")? {
+        config.countdown_frames = frames;
+    }
+    if let Some(frames) = parse_flag("--round-pause")? {
+        config.round_over_frames = frames;
+    }
+    if let Some(pct) = parse_pct_flag("--start-health")? {
+        config.start_health = pct;
+    }
+    if let Some(pct) = parse_pct_flag("--start-stamina")? {
+        config.start_stamina = pct;
+    }
+    for (player, handicap) in config.handicaps.iter_mut().enumerate() {
+        if let Some(pct) = parse_pct_flag(&format!("--p{}-health", player + 1))? {
+            handicap.health = pct;
+        }
+        if let Some(pct) = parse_pct_flag(&format!("--p{}-damage", player + 1))? {
+            handicap.damage = pct;
+        }
+    }
+    if let Some(name) = flag_value("--rules") {
+        let preset: RulesPreset = name
+            .parse()
+            .map_err(|err: UnknownRulesPreset| invalid_input(err.to_string()))?;
+        config.rules = preset.rules();
+    }
+    if std::env::args().any(|arg| arg == "--free-depth") {
+        config.depth_mode = DepthMode::Free;
+    }
+    Ok(config)
+}
+
+fn run_game(stdout: &mut io::Stdout, template: &GameState) -> io::Result<()> {
+    let mut game = template.clone();
     let mut p1_selection: usize = 0;
     let mut p2_selection: usize = 1;
     let mut select_phase: u8 = 0; // 0 = P1 selecting, 1 = P2 selecting
@@ -64,7 +239,7 @@ fn run_game(stdout: &mut io::Stdout) -> io::Result<()> {
                     }
                     GamePhase::MatchOver => {
                         if key.code == KeyCode::Enter || key.code == KeyCode::Char(' ') {
-                            game = GameState::new_in_select();
+                            game = template.clone();
                             p1_selection = 0;
                             p2_selection = 1;
                             select_phase = 0;
@@ -143,6 +318,8 @@ fn apply_key_to_input(key: KeyEvent, p1: &mut InputState, p2: &mut InputState) {
         KeyCode::Char('l') => p1.special = true,
         KeyCode::Char(' ') => p1.block = true,
         KeyCode::Tab => p1.dash = true,
+        KeyCode::Char('e') => p1.pickup = true,
+        KeyCode::Char('q') => p1.stance = true,
 
         // Player 2: Arrows + ,./ + 0 + backslash
         KeyCode::Up => p2.move_forward = true,
@@ -154,6 +331,8 @@ fn apply_key_to_input(key: KeyEvent, p1: &mut InputState, p2: &mut InputState) {
         KeyCode::Char('/') => p2.special = true,
         KeyCode::Char('0') => p2.block = true,
         KeyCode::Char('\\') => p2.dash = true,
+        KeyCode::Char('m') => p2.pickup = true,
+        KeyCode::Char('n') => p2.stance = true,
 
         _ => {}
     }
@@ -166,7 +345,11 @@ fn render(
     p2_sel: usize,
     sel_phase: u8,
 ) -> io::Result<()> {
-    execute!(stdout, cursor::MoveTo(0, 0), terminal::Clear(ClearType::All))?;
+    execute!(
+        stdout,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(ClearType::All)
+    )?;
 
     match game.phase {
         GamePhase::FighterSelect => render_select(stdout, p1_sel, p2_sel, sel_phase),
@@ -186,16 +369,31 @@ fn render_select(
     let fighters = FighterId::ALL;
 
     write!(stdout, "\r\n")?;
-    write!(stdout, "  ╔══════════════════════════════════════════════════════════╗\r\n")?;
-    write!(stdout, "  ║              WAR VILLAGE - SELECT FIGHTER               ║\r\n")?;
-    write!(stdout, "  ╚══════════════════════════════════════════════════════════╝\r\n")?;
+    write!(
+        stdout,
+        "  ╔══════════════════════════════════════════════════════════╗\r\n"
+    )?;
+    write!(
+        stdout,
+        "  ║              WAR VILLAGE - SELECT FIGHTER               ║\r\n"
+    )?;
+    write!(
+        stdout,
+        "  ╚══════════════════════════════════════════════════════════╝\r\n"
+    )?;
     write!(stdout, "\r\n")?;
 
     if phase == 0 {
-        write!(stdout, "  >> PLAYER 1: Choose your fighter (W/S + Enter)\r\n")?;
+        write!(
+            stdout,
+            "  >> PLAYER 1: Choose your fighter (W/S + Enter)\r\n"
+        )?;
     } else {
         write!(stdout, "  Player 1: {}\r\n", fighters[p1_sel])?;
-        write!(stdout, "  >> PLAYER 2: Choose your fighter (Up/Down + Enter)\r\n")?;
+        write!(
+            stdout,
+            "  >> PLAYER 2: Choose your fighter (Up/Down + Enter)\r\n"
+        )?;
     }
     write!(stdout, "\r\n")?;
 
@@ -207,7 +405,12 @@ fn render_select(
         write!(
             stdout,
             "  {} {:<8} | {:?} | HP:{:.0} SPD:{:.1} DEF:{:.2} | {}\r\n",
-            marker, fighter_id, data.style, data.max_health, data.move_speed, data.defense,
+            marker,
+            fighter_id,
+            data.style,
+            data.max_health,
+            data.move_speed,
+            data.stats.defense,
             data.default_weapon,
         )?;
     }
@@ -219,9 +422,17 @@ fn render_select(
 fn render_countdown(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()> {
     render_hud(stdout, game)?;
     write!(stdout, "\r\n")?;
-    write!(stdout, "                    === {} ===\r\n", game.countdown_display())?;
+    write!(
+        stdout,
+        "                    === {} ===\r\n",
+        game.countdown_display()
+    )?;
     write!(stdout, "\r\n")?;
-    write!(stdout, "         Round {} of Best-of-3\r\n", game.current_round)?;
+    write!(
+        stdout,
+        "         Round {} of Best-of-3\r\n",
+        game.current_round
+    )?;
     stdout.flush()
 }
 
@@ -249,9 +460,10 @@ fn render_hud(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()> {
     write!(stdout, "\r\n")?;
     write!(
         stdout,
-        "  Round {}  |  Time: {:.0}s  |  Wins: P1[{}] - P2[{}]\r\n",
+        "  Round {}  |  Time: {}  |  Wins: P1[{}] - P2[{}]\r\n",
         game.current_round,
-        game.round_time_remaining(),
+        game.round_time_remaining()
+            .map_or("∞".to_string(), |seconds| format!("{:.0}s", seconds)),
         p1.round_wins,
         p2.round_wins,
     )?;
@@ -278,9 +490,53 @@ fn render_hud(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()> {
         p1_stam, p1.stamina, p2_stam, p2.stamina,
     )?;
 
+    let p1_meter = meter_bar(p1.meter, 7);
+    let p2_meter = meter_bar(p2.meter, 7);
+    write!(
+        stdout,
+        "  SM [{}] x{:<5}    SM [{}] x{}\r\n",
+        p1_meter,
+        p1.meter_bars(),
+        p2_meter,
+        p2.meter_bars(),
+    )?;
+
+    write!(
+        stdout,
+        "  {:<33}    {}\r\n",
+        resource_label(p1),
+        resource_label(p2),
+    )?;
+
+    // Each side shows the combo it's landing on the other
+    write!(
+        stdout,
+        "  {:<33}    {}\r\n",
+        combo_label(p2),
+        combo_label(p1),
+    )?;
+
     Ok(())
 }
 
+/// The combo `defender` is taking, e.g. `4 HITS  18.5 dmg`.
+fn combo_label(defender: &wv_core::game::Fighter) -> String {
+    defender.combo.current().map_or(String::new(), |c| {
+        format!("{} HITS  {:.1} dmg", c.hits, c.damage)
+    })
+}
+
+/// The fighter's own gauges, e.g. `Mana [======----]`.
+fn resource_label(fighter: &wv_core::game::Fighter) -> String {
+    fighter
+        .behavior
+        .resources()
+        .iter()
+        .map(|r| format!("{} [{}]", r.name, stamina_bar(r.value / r.max, 10)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 fn health_bar(pct: f32, width: usize) -> String {
     let filled = (pct * width as f32).round() as usize;
     let empty = width.saturating_sub(filled);
@@ -293,6 +549,18 @@ fn stamina_bar(pct: f32, width: usize) -> String {
     format!("{}{}", "=".repeat(filled), "-".repeat(empty))
 }
 
+/// Super meter split into one segment per bar, e.g. `=======|===----|-------`.
+fn meter_bar(meter: f32, bar_width: usize) -> String {
+    (0..METER_BARS)
+        .map(|i| {
+            let in_bar = ((meter - i as f32 * METER_PER_BAR) / METER_PER_BAR).clamp(0.0, 1.0);
+            let filled = (in_bar * bar_width as f32).round() as usize;
+            format!("{}{}", "=".repeat(filled), "-".repeat(bar_width - filled))
+        })
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
 fn render_arena(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()> {
     let arena_width: usize = 60;
     let p1_pos = &game.fighters[0].physics.position;
@@ -324,6 +592,12 @@ fn render_arena(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()> {
     }
 
     let mut ground = vec![' '; arena_width];
+    for item in &game.items {
+        let col = ((item.physics.position.x - ARENA_MIN_X) / range * (arena_width - 1) as f32)
+            .round()
+            .clamp(0.0, (arena_width - 1) as f32) as usize;
+        ground[col] = '*';
+    }
     let p1_char = fighter_char(&game.fighters[0], '1');
     let p2_char = fighter_char(&game.fighters[1], '2');
 
@@ -352,7 +626,10 @@ fn fighter_char(fighter: &wv_core::game::Fighter, default: char) -> char {
         FighterState::Dashing => 'D',
         FighterState::HitStun => 'H',
         FighterState::Knockdown => '_',
+        FighterState::Swept => ',',
         FighterState::GettingUp => '^',
+        FighterState::Falling => 'v',
+        FighterState::TechRoll => 'r',
         _ => default,
     }
 }
@@ -364,40 +641,77 @@ fn render_state_info(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()
     write!(
         stdout,
         "  P1: {:<12} Facing: {:<6}       P2: {:<12} Facing: {}\r\n",
-        p1.state_machine.state,
-        if p1.facing == wv_core::types::Facing::Right { "Right" } else { "Left" },
-        p2.state_machine.state,
-        if p2.facing == wv_core::types::Facing::Right { "Right" } else { "Left" },
+        state_label(p1),
+        if p1.facing == wv_core::types::Facing::Right {
+            "Right"
+        } else {
+            "Left"
+        },
+        state_label(p2),
+        if p2.facing == wv_core::types::Facing::Right {
+            "Right"
+        } else {
+            "Left"
+        },
     )?;
     write!(
         stdout,
         "  Pos: ({:.1}, {:.1})                      Pos: ({:.1}, {:.1})\r\n",
-        p1.physics.position.x, p1.physics.position.y,
-        p2.physics.position.x, p2.physics.position.y,
+        p1.physics.position.x, p1.physics.position.y, p2.physics.position.x, p2.physics.position.y,
     )?;
 
     Ok(())
 }
 
+fn state_label(fighter: &wv_core::game::Fighter) -> String {
+    let mut label = fighter.state_machine.state.to_string();
+    if let Some(stance) = fighter.stance() {
+        label.push_str(&format!(" <{}>", stance.name));
+    }
+    if fighter.is_exhausted() {
+        label.push_str(" (EXH)");
+    }
+    for status in fighter.status.iter() {
+        label.push_str(&format!(" [{}]", status.effect.kind));
+    }
+    label
+}
+
 fn render_round_over(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()> {
     render_hud(stdout, game)?;
     write!(stdout, "\r\n")?;
 
-    let p1_dead = !game.fighters[0].is_alive();
-    let p2_dead = !game.fighters[1].is_alive();
-
-    if p1_dead && p2_dead {
-        write!(stdout, "          === DOUBLE KO! ===\r\n")?;
-    } else if p1_dead {
-        write!(stdout, "          === PLAYER 2 WINS THE ROUND! ===\r\n")?;
-    } else if p2_dead {
-        write!(stdout, "          === PLAYER 1 WINS THE ROUND! ===\r\n")?;
-    } else if game.fighters[0].health > game.fighters[1].health {
-        write!(stdout, "          === TIME! PLAYER 1 WINS! ===\r\n")?;
-    } else if game.fighters[1].health > game.fighters[0].health {
-        write!(stdout, "          === TIME! PLAYER 2 WINS! ===\r\n")?;
-    } else {
-        write!(stdout, "          === TIME! DRAW! ===\r\n")?;
+    let Some(result) = game.round_result else {
+        return stdout.flush();
+    };
+
+    match (result.reason, result.winner) {
+        (RoundEndReason::Knockout, None) => {
+            write!(stdout, "          === DOUBLE KO! ===\r\n")?;
+        }
+        (RoundEndReason::Knockout, Some(w)) => {
+            write!(
+                stdout,
+                "          === PLAYER {} WINS THE ROUND! ===\r\n",
+                w + 1
+            )?;
+        }
+        (RoundEndReason::RingOut, None) => {
+            write!(stdout, "          === DOUBLE RING OUT! ===\r\n")?;
+        }
+        (RoundEndReason::RingOut, Some(w)) => {
+            write!(
+                stdout,
+                "          === RING OUT! PLAYER {} WINS! ===\r\n",
+                w + 1
+            )?;
+        }
+        (RoundEndReason::TimeUp, None) => {
+            write!(stdout, "          === TIME! DRAW! ===\r\n")?;
+        }
+        (RoundEndReason::TimeUp, Some(w)) => {
+            write!(stdout, "          === TIME! PLAYER {} WINS! ===\r\n", w + 1)?;
+        }
     }
 
     stdout.flush()
@@ -405,18 +719,25 @@ fn render_round_over(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()
 
 fn render_match_over(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()> {
     write!(stdout, "\r\n")?;
-    write!(stdout, "  ╔══════════════════════════════════════════════════════════╗\r\n")?;
+    write!(
+        stdout,
+        "  ╔══════════════════════════════════════════════════════════╗\r\n"
+    )?;
 
     if let Some(winner) = game.winner() {
         let name = &game.fighters[winner].data.id;
         write!(
             stdout,
             "  ║    PLAYER {} ({}) WINS THE MATCH!                        ║\r\n",
-            winner + 1, name,
+            winner + 1,
+            name,
         )?;
     }
 
-    write!(stdout, "  ╚══════════════════════════════════════════════════════════╝\r\n")?;
+    write!(
+        stdout,
+        "  ╚══════════════════════════════════════════════════════════╝\r\n"
+    )?;
     write!(stdout, "\r\n")?;
     write!(
         stdout,
@@ -429,11 +750,29 @@ fn render_match_over(stdout: &mut io::Stdout, game: &GameState) -> io::Result<()
 }
 
 fn render_controls(stdout: &mut io::Stdout) -> io::Result<()> {
-    write!(stdout, "  ─── Controls ────────────────────────────────────\r\n")?;
+    write!(
+        stdout,
+        "  ─── Controls ────────────────────────────────────\r\n"
+    )?;
     write!(stdout, "  P1: WASD=Move  J=Light K=Heavy L=Special\r\n")?;
-    write!(stdout, "      Space=Block  Tab=Dash\r\n")?;
+    write!(
+        stdout,
+        "      Space=Block  Tab=Dash  K+L=Enhanced Special (1/2 bar)\r\n"
+    )?;
     write!(stdout, "  P2: Arrows=Move  ,=Light .=Heavy /=Special\r\n")?;
-    write!(stdout, "      0=Block  \\=Dash\r\n")?;
+    write!(
+        stdout,
+        "      0=Block  \\=Dash  .+/=Enhanced Special (1/2 bar)\r\n"
+    )?;
+    write!(
+        stdout,
+        "  Pickup weapon (*): P1=E  P2=M   Stance: P1=Q  P2=N\r\n"
+    )?;
+    write!(stdout, "  Dash+Back=Backdash  Dash+Left/Right=Sidestep\r\n")?;
+    write!(
+        stdout,
+        "  Down: Dash=Quick rise  Dash+Fwd/Back=Tech roll  Block=Stay down\r\n"
+    )?;
     write!(stdout, "  [ESC] Quit\r\n")?;
     Ok(())
 }
@@ -0,0 +1,459 @@
+use crate::fighter::FighterId;
+use crate::input::InputState;
+use crate::state_machine::{ActiveAttack, FighterState};
+
+const RAGE_MAX: f32 = 30.0; // damage Kael must take to fill rage
+const RAGE_DAMAGE: f32 = 1.3; // damage multiplier on the hit that spends full rage
+const SHIELD_MAX: f32 = 100.0;
+const SHIELD_WEAR: f32 = 8.0; // durability lost per point of blocked damage
+const SHIELD_REGEN: f32 = 0.2; // per frame while not blocking
+const SHIELD_CHIP: f32 = 0.5; // chip damage multiplier while the shield holds
+const MANA_MAX: f32 = 100.0;
+const MANA_REGEN: f32 = 0.15; // per frame
+const MANA_CHANNEL_RATE: f32 = 4.0; // regen multiplier while channeling
+const SPELL_MANA_COST: f32 = 25.0; // specials and enhanced specials
+const SUPER_MANA_COST: f32 = 40.0;
+const MOMENTUM_MAX_STACKS: u32 = 3;
+const MOMENTUM_DAMAGE: f32 = 0.1; // extra damage per momentum stack
+const RHYTHM_FRAMES: f32 = 45.0; // frames of footwork to reach full rhythm
+const RHYTHM_STARTUP: f32 = 0.7; // startup multiplier for the attack that spends full rhythm
+
+/// A character-specific gauge, reported to frontends next to health and stamina.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resource {
+    pub name: &'static str,
+    pub value: f32,
+    pub max: f32,
+}
+
+/// What a behavior wants done with this frame's input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputOverride {
+    /// Run the normal input handling.
+    Default,
+    /// The input was used by the behavior: stand still and skip normal handling.
+    Hold,
+}
+
+/// Hooks for per-fighter mechanics on top of the shared combat rules.
+///
+/// Every hook has a neutral default, so a behavior only overrides what it needs.
+/// Query hooks (`&self`) are asked while hits are detected; the `on_*` hooks
+/// run once the outcome is known and are where resources are spent or gained.
+pub trait FighterBehavior: std::fmt::Debug {
+    /// Gauges to show in the HUD and snapshot.
+    fn resources(&self) -> Vec<Resource> {
+        Vec::new()
+    }
+
+    /// Advance one frame (not called during hitstop).
+    fn tick(&mut self, _state: FighterState) {}
+
+    /// Called before the normal input handling whenever the fighter can act.
+    fn handle_input(&mut self, _input: &InputState) -> InputOverride {
+        InputOverride::Default
+    }
+
+    /// Whether the fighter may start `attack` right now.
+    fn can_start_attack(&self, _attack: ActiveAttack) -> bool {
+        true
+    }
+
+    /// Multiplier on the startup frames of `attack`.
+    fn startup_multiplier(&self, _attack: ActiveAttack) -> f32 {
+        1.0
+    }
+
+    fn on_attack_started(&mut self, _attack: ActiveAttack) {}
+
+    /// Multiplier on damage this fighter deals with the next hit.
+    fn damage_dealt_multiplier(&self) -> f32 {
+        1.0
+    }
+
+    /// Multiplier on damage this fighter takes from the next hit.
+    fn damage_taken_multiplier(&self, _blocked: bool) -> f32 {
+        1.0
+    }
+
+    fn on_hit_landed(&mut self, _damage: f32, _blocked: bool) {}
+
+    fn on_hit_taken(&mut self, _damage: f32, _blocked: bool) {}
+
+    /// Restore the behavior's starting state for a new round.
+    fn reset_round(&mut self);
+
+    fn clone_box(&self) -> Box<dyn FighterBehavior>;
+}
+
+impl Clone for Box<dyn FighterBehavior> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// The built-in mechanic for each fighter.
+pub fn for_fighter(id: FighterId) -> Box<dyn FighterBehavior> {
+    match id {
+        FighterId::Kael => Box::new(Rage::default()),
+        FighterId::Knight => Box::new(Shield::default()),
+        FighterId::Zara => Box::new(Mana::default()),
+        FighterId::Magnus => Box::new(Momentum::default()),
+        FighterId::Orin => Box::new(Rhythm::default()),
+    }
+}
+
+/// Kael: damage taken fills rage; the first hit landed at full rage hits harder.
+#[derive(Debug, Clone, Default)]
+pub struct Rage {
+    pub rage: f32,
+}
+
+impl Rage {
+    fn is_full(&self) -> bool {
+        self.rage >= RAGE_MAX
+    }
+}
+
+impl FighterBehavior for Rage {
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource {
+            name: "Rage",
+            value: self.rage,
+            max: RAGE_MAX,
+        }]
+    }
+
+    fn damage_dealt_multiplier(&self) -> f32 {
+        if self.is_full() {
+            RAGE_DAMAGE
+        } else {
+            1.0
+        }
+    }
+
+    fn on_hit_landed(&mut self, _damage: f32, _blocked: bool) {
+        if self.is_full() {
+            self.rage = 0.0;
+        }
+    }
+
+    fn on_hit_taken(&mut self, damage: f32, blocked: bool) {
+        if !blocked {
+            self.rage = (self.rage + damage).min(RAGE_MAX);
+        }
+    }
+
+    fn reset_round(&mut self) {
+        self.rage = 0.0;
+    }
+
+    fn clone_box(&self) -> Box<dyn FighterBehavior> {
+        Box::new(self.clone())
+    }
+}
+
+/// Knight: the shield halves chip damage until blocking wears it down.
+/// It recovers while the Knight isn't blocking.
+#[derive(Debug, Clone)]
+pub struct Shield {
+    pub durability: f32,
+}
+
+impl Default for Shield {
+    fn default() -> Self {
+        Self {
+            durability: SHIELD_MAX,
+        }
+    }
+}
+
+impl FighterBehavior for Shield {
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource {
+            name: "Shield",
+            value: self.durability,
+            max: SHIELD_MAX,
+        }]
+    }
+
+    fn tick(&mut self, state: FighterState) {
+        if state != FighterState::Blocking {
+            self.durability = (self.durability + SHIELD_REGEN).min(SHIELD_MAX);
+        }
+    }
+
+    fn damage_taken_multiplier(&self, blocked: bool) -> f32 {
+        if blocked && self.durability > 0.0 {
+            SHIELD_CHIP
+        } else {
+            1.0
+        }
+    }
+
+    fn on_hit_taken(&mut self, damage: f32, blocked: bool) {
+        if blocked {
+            self.durability = (self.durability - damage * SHIELD_WEAR).max(0.0);
+        }
+    }
+
+    fn reset_round(&mut self) {
+        *self = Self::default();
+    }
+
+    fn clone_box(&self) -> Box<dyn FighterBehavior> {
+        Box::new(self.clone())
+    }
+}
+
+/// Zara: spells cost mana. Holding block + special channels to refill it faster.
+#[derive(Debug, Clone)]
+pub struct Mana {
+    pub mana: f32,
+    pub channeling: bool,
+}
+
+impl Mana {
+    fn cost(attack: ActiveAttack) -> f32 {
+        match attack {
+            ActiveAttack::Special | ActiveAttack::EnhancedSpecial => SPELL_MANA_COST,
+            ActiveAttack::Super => SUPER_MANA_COST,
+            _ => 0.0,
+        }
+    }
+}
+
+impl Default for Mana {
+    fn default() -> Self {
+        Self {
+            mana: MANA_MAX,
+            channeling: false,
+        }
+    }
+}
+
+impl FighterBehavior for Mana {
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource {
+            name: "Mana",
+            value: self.mana,
+            max: MANA_MAX,
+        }]
+    }
+
+    fn tick(&mut self, _state: FighterState) {
+        let rate = if self.channeling {
+            MANA_REGEN * MANA_CHANNEL_RATE
+        } else {
+            MANA_REGEN
+        };
+        self.mana = (self.mana + rate).min(MANA_MAX);
+    }
+
+    fn handle_input(&mut self, input: &InputState) -> InputOverride {
+        self.channeling = input.block && input.special;
+        if self.channeling {
+            InputOverride::Hold
+        } else {
+            InputOverride::Default
+        }
+    }
+
+    fn can_start_attack(&self, attack: ActiveAttack) -> bool {
+        self.mana >= Self::cost(attack)
+    }
+
+    fn on_attack_started(&mut self, attack: ActiveAttack) {
+        self.mana -= Self::cost(attack);
+        self.channeling = false;
+    }
+
+    fn on_hit_taken(&mut self, _damage: f32, _blocked: bool) {
+        self.channeling = false;
+    }
+
+    fn reset_round(&mut self) {
+        *self = Self::default();
+    }
+
+    fn clone_box(&self) -> Box<dyn FighterBehavior> {
+        Box::new(self.clone())
+    }
+}
+
+/// Magnus: every clean hit adds a momentum stack for more damage; getting hit loses it all.
+#[derive(Debug, Clone, Default)]
+pub struct Momentum {
+    pub stacks: u32,
+}
+
+impl FighterBehavior for Momentum {
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource {
+            name: "Momentum",
+            value: self.stacks as f32,
+            max: MOMENTUM_MAX_STACKS as f32,
+        }]
+    }
+
+    fn damage_dealt_multiplier(&self) -> f32 {
+        1.0 + self.stacks as f32 * MOMENTUM_DAMAGE
+    }
+
+    fn on_hit_landed(&mut self, _damage: f32, blocked: bool) {
+        if !blocked {
+            self.stacks = (self.stacks + 1).min(MOMENTUM_MAX_STACKS);
+        }
+    }
+
+    fn on_hit_taken(&mut self, _damage: f32, blocked: bool) {
+        if !blocked {
+            self.stacks = 0;
+        }
+    }
+
+    fn reset_round(&mut self) {
+        self.stacks = 0;
+    }
+
+    fn clone_box(&self) -> Box<dyn FighterBehavior> {
+        Box::new(self.clone())
+    }
+}
+
+/// Orin: footwork builds rhythm; at full rhythm the next attack comes out faster.
+///
+/// Separate from his Ginga stance, which changes his moveset rather than his timing.
+#[derive(Debug, Clone, Default)]
+pub struct Rhythm {
+    pub rhythm: f32,
+}
+
+impl Rhythm {
+    fn is_full(&self) -> bool {
+        self.rhythm >= RHYTHM_FRAMES
+    }
+}
+
+impl FighterBehavior for Rhythm {
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource {
+            name: "Rhythm",
+            value: self.rhythm,
+            max: RHYTHM_FRAMES,
+        }]
+    }
+
+    fn tick(&mut self, state: FighterState) {
+        if state == FighterState::Moving {
+            self.rhythm = (self.rhythm + 1.0).min(RHYTHM_FRAMES);
+        }
+    }
+
+    fn startup_multiplier(&self, _attack: ActiveAttack) -> f32 {
+        if self.is_full() {
+            RHYTHM_STARTUP
+        } else {
+            1.0
+        }
+    }
+
+    fn on_attack_started(&mut self, _attack: ActiveAttack) {
+        self.rhythm = 0.0;
+    }
+
+    fn on_hit_taken(&mut self, _damage: f32, _blocked: bool) {
+        self.rhythm = 0.0;
+    }
+
+    fn reset_round(&mut self) {
+        self.rhythm = 0.0;
+    }
+
+    fn clone_box(&self) -> Box<dyn FighterBehavior> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_rage_boosts_one_hit() {
+        let mut rage = Rage::default();
+        rage.on_hit_taken(RAGE_MAX, true);
+        assert_eq!(rage.damage_dealt_multiplier(), 1.0);
+        rage.on_hit_taken(RAGE_MAX, false);
+        assert_eq!(rage.damage_dealt_multiplier(), RAGE_DAMAGE);
+        rage.on_hit_landed(5.0, false);
+        assert_eq!(rage.damage_dealt_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn shield_wears_out_and_recovers() {
+        let mut shield = Shield::default();
+        assert_eq!(shield.damage_taken_multiplier(true), SHIELD_CHIP);
+        assert_eq!(shield.damage_taken_multiplier(false), 1.0);
+        shield.on_hit_taken(SHIELD_MAX, true);
+        assert_eq!(shield.damage_taken_multiplier(true), 1.0);
+        shield.tick(FighterState::Blocking);
+        assert_eq!(shield.durability, 0.0);
+        shield.tick(FighterState::Idle);
+        assert!(shield.durability > 0.0);
+    }
+
+    #[test]
+    fn spells_need_mana() {
+        let mut mana = Mana::default();
+        for _ in 0..4 {
+            assert!(mana.can_start_attack(ActiveAttack::Special));
+            mana.on_attack_started(ActiveAttack::Special);
+        }
+        assert!(!mana.can_start_attack(ActiveAttack::Special));
+        assert!(mana.can_start_attack(ActiveAttack::Light));
+    }
+
+    #[test]
+    fn channeling_refills_mana_faster() {
+        let input = InputState {
+            block: true,
+            special: true,
+            ..Default::default()
+        };
+        let mut channeled = Mana {
+            mana: 0.0,
+            channeling: false,
+        };
+        let mut idle = channeled.clone();
+        assert_eq!(channeled.handle_input(&input), InputOverride::Hold);
+        channeled.tick(FighterState::Idle);
+        idle.tick(FighterState::Idle);
+        assert!(channeled.mana > idle.mana);
+    }
+
+    #[test]
+    fn momentum_stacks_until_hit() {
+        let mut momentum = Momentum::default();
+        for _ in 0..5 {
+            momentum.on_hit_landed(5.0, false);
+        }
+        assert_eq!(momentum.stacks, MOMENTUM_MAX_STACKS);
+        momentum.on_hit_taken(5.0, false);
+        assert_eq!(momentum.damage_dealt_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn full_rhythm_speeds_up_next_attack() {
+        let mut rhythm = Rhythm::default();
+        for _ in 0..RHYTHM_FRAMES as u32 {
+            rhythm.tick(FighterState::Moving);
+        }
+        assert_eq!(
+            rhythm.startup_multiplier(ActiveAttack::Light),
+            RHYTHM_STARTUP
+        );
+        rhythm.on_attack_started(ActiveAttack::Light);
+        assert_eq!(rhythm.startup_multiplier(ActiveAttack::Light), 1.0);
+    }
+}
@@ -1,35 +1,110 @@
-use crate::fighter::AttackData;
+use crate::fighter::{AttackData, Resistances, StyleModifiers};
+use crate::rules::CombatRules;
+use crate::stats::StatBlock;
 use crate::types::{Facing, Vec3, AABB};
-use crate::weapon::WeaponData;
+use crate::weapon::{DamageType, WeaponData};
 
-const BLOCK_DAMAGE_REDUCTION: f32 = 0.2; // blocked attacks deal 20% of normal damage
-const HITSTUN_BASE_FRAMES: u32 = 12;
 const LAUNCH_VELOCITY_Y: f32 = 8.0;
+const HITSTOP_BASE_FRAMES: u32 = 4;
+const HITSTOP_PER_KNOCKBACK: f32 = 0.5; // extra freeze frames per unit of knockback force
+
+/// How a hit's damage was built up: `total` is the product of every factor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageBreakdown {
+    pub damage_type: DamageType,
+    pub base: f32,       // weapon base damage x move multiplier
+    pub stale: f32,      // stale move and enhanced special scaling
+    pub stats: f32,      // attacker attack / defender defense, with style bonuses
+    pub resistance: f32, // defender's resistance to the damage type
+    pub guard: f32,      // chip reduction when blocked (1.0 on a clean hit)
+    pub combo: f32,      // combo scaling
+    pub bonus: f32,      // fighter mechanics such as rage or a shield
+    pub total: f32,
+}
+
+impl std::fmt::Display for DamageBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.1} {} (base {:.1} x{:.2} stale x{:.2} stats x{:.2} resist",
+            self.total, self.damage_type, self.base, self.stale, self.stats, self.resistance,
+        )?;
+        if self.guard < 1.0 {
+            write!(f, " x{:.2} guard", self.guard)?;
+        }
+        if self.combo < 1.0 {
+            write!(f, " x{:.2} combo", self.combo)?;
+        }
+        if self.bonus != 1.0 {
+            write!(f, " x{:.2} bonus", self.bonus)?;
+        }
+        write!(f, ")")
+    }
+}
 
 /// Result of a hit check between an attacker and defender.
 #[derive(Debug, Clone)]
 pub struct HitResult {
     pub damage: f32,
+    pub breakdown: DamageBreakdown,
     pub knockback: Vec3,
     pub hitstun_frames: u32,
     pub was_blocked: bool,
     pub launches: bool,
+    /// Frames both fighters freeze on impact.
+    pub hitstop_frames: u32,
+}
+
+impl HitResult {
+    /// Scale the damage by a fighter-mechanic bonus, keeping the breakdown in step.
+    pub fn scale_damage(&mut self, factor: f32) {
+        self.damage *= factor;
+        self.breakdown.bonus *= factor;
+        self.breakdown.total = self.damage;
+    }
+}
+
+/// Hitstop for an attack: its own value if set, otherwise scaled by how hard it hits.
+pub fn hitstop_frames(attack: &AttackData) -> u32 {
+    attack
+        .hitstop_frames
+        .unwrap_or(HITSTOP_BASE_FRAMES + (attack.knockback_force * HITSTOP_PER_KNOCKBACK) as u32)
+}
+
+/// The attacking side of a hit check.
+#[derive(Debug, Clone, Copy)]
+pub struct AttackerContext<'a> {
+    pub position: Vec3,
+    pub facing: Facing,
+    pub weapon: &'a WeaponData,
+    /// Effective stats, with buffs and debuffs already applied.
+    pub stats: StatBlock,
+    pub style: &'a StyleModifiers,
+    pub advancing: bool,
+    /// 1.0 for a fresh attack, lower for one used repeatedly.
+    pub stale_multiplier: f32,
+}
+
+/// The defending side of a hit check.
+#[derive(Debug, Clone, Copy)]
+pub struct DefenderContext<'a> {
+    pub position: Vec3,
+    pub hurtbox: AABB, // local-space, relative to `position`
+    /// Effective stats, with buffs and debuffs already applied.
+    pub stats: StatBlock,
+    pub style: &'a StyleModifiers,
+    pub resistances: &'a Resistances,
+    pub blocking: bool,
+    /// Consecutive hits already taken, for combo scaling.
+    pub combo_hits: u32,
 }
 
 /// Build the world-space hitbox for an attack given the attacker's position and facing.
-pub fn attack_hitbox(
-    attacker_pos: Vec3,
-    facing: Facing,
-    attack: &AttackData,
-    _weapon: &WeaponData,
-) -> AABB {
+pub fn attack_hitbox(attacker_pos: Vec3, facing: Facing, attack: &AttackData) -> AABB {
     let mut offset = attack.hitbox_offset;
     offset.x *= facing.sign();
 
-    AABB::from_center(
-        attacker_pos + offset,
-        attack.hitbox_half_extents,
-    )
+    AABB::from_center(attacker_pos + offset, attack.hitbox_half_extents)
 }
 
 /// Build the world-space hurtbox for a defender.
@@ -39,46 +114,57 @@ pub fn defender_hurtbox(defender_pos: Vec3, hurtbox_local: &AABB) -> AABB {
 
 /// Calculate damage and effects of a hit.
 ///
-/// `combo_hits` is how many consecutive hits the defender has already taken
-/// (used for combo scaling). `stale_multiplier` scales damage for repeated
-/// use of the same attack (1.0 = fresh, lower = stale).
+/// The damage type comes from the attack, falling back to the attacker's
+/// weapon. Combo scaling, block reduction and hitstun come from `rules`.
 pub fn calculate_hit(
     attack: &AttackData,
-    weapon: &WeaponData,
-    attacker_defense: f32,
-    defender_defense: f32,
-    is_blocking: bool,
-    attacker_pos: Vec3,
-    defender_pos: Vec3,
-    combo_hits: u32,
-    stale_multiplier: f32,
+    attacker: &AttackerContext,
+    defender: &DefenderContext,
+    rules: &CombatRules,
 ) -> HitResult {
-    // Combo scaling: each hit in a combo does 15% less damage, minimum 40%
-    let combo_damage_scale = (1.0 - combo_hits as f32 * 0.15).max(0.4);
-    // Combo hitstun scaling: each hit gives 25% less hitstun, minimum 30%
-    let combo_hitstun_scale = (1.0 - combo_hits as f32 * 0.25).max(0.3);
-
-    let raw_damage = weapon.base_damage * attack.damage_multiplier * stale_multiplier;
-    let damage = if is_blocking {
-        raw_damage * BLOCK_DAMAGE_REDUCTION * defender_defense
+    let is_blocking = defender.blocking;
+    let combo_damage_scale = rules.combo_damage.factor(defender.combo_hits);
+    let combo_hitstun_scale = rules.combo_hitstun.factor(defender.combo_hits);
+
+    let damage_type = attack.damage_type.unwrap_or(attacker.weapon.damage_type);
+    let base = attacker.weapon.base_damage * attack.damage_multiplier;
+    let mut stats = attacker.stats.attack / defender.stats.defense;
+    if attacker.advancing {
+        stats *= attacker.style.advancing_damage;
+    }
+    let resistance = defender.resistances.get(damage_type);
+    let (guard, combo) = if is_blocking {
+        (rules.block_damage * defender.style.chip_damage, 1.0)
     } else {
-        raw_damage * defender_defense * combo_damage_scale
+        (1.0, combo_damage_scale)
+    };
+    let damage = base * attacker.stale_multiplier * stats * resistance * guard * combo;
+    let breakdown = DamageBreakdown {
+        damage_type,
+        base,
+        stale: attacker.stale_multiplier,
+        stats,
+        resistance,
+        guard,
+        combo,
+        bonus: 1.0,
+        total: damage,
     };
 
     // Knockback direction: push defender away from attacker
-    let dir_x = if defender_pos.x >= attacker_pos.x {
+    let dir_x = if defender.position.x >= attacker.position.x {
         1.0
     } else {
         -1.0
     };
 
     // Knockback increases with combo hits to push fighters apart
-    let combo_knockback_boost = 1.0 + combo_hits as f32 * 0.2;
+    let combo_knockback_boost = rules.combo_knockback.factor(defender.combo_hits);
     let knockback_magnitude = if is_blocking {
-        attack.knockback_force * 0.3
+        attack.knockback_force * rules.block_knockback
     } else {
         attack.knockback_force * combo_knockback_boost
-    };
+    } * (1.0 - defender.stats.knockback_resistance);
 
     let launches = attack.launches && !is_blocking;
 
@@ -89,53 +175,37 @@ pub fn calculate_hit(
     );
 
     let base_hitstun = if is_blocking {
-        HITSTUN_BASE_FRAMES / 2
+        rules.blockstun_frames
     } else {
-        HITSTUN_BASE_FRAMES + (attack.knockback_force as u32)
+        attack
+            .hitstun_frames
+            .unwrap_or(rules.hitstun_frames + attack.knockback_force as u32)
     };
     let hitstun_frames = ((base_hitstun as f32) * combo_hitstun_scale) as u32;
 
-    // Use attacker_defense to suppress the warning (it could scale damage in the future)
-    let _ = attacker_defense;
-
     HitResult {
         damage,
+        breakdown,
         knockback,
         hitstun_frames,
         was_blocked: is_blocking,
         launches,
+        hitstop_frames: hitstop_frames(attack),
     }
 }
 
 /// Check if an attack hits a defender, and if so return the hit result.
 pub fn check_hit(
-    attacker_pos: Vec3,
-    attacker_facing: Facing,
     attack: &AttackData,
-    weapon: &WeaponData,
-    attacker_defense: f32,
-    defender_pos: Vec3,
-    defender_hurtbox_local: &AABB,
-    defender_defense: f32,
-    defender_blocking: bool,
-    combo_hits: u32,
-    stale_multiplier: f32,
+    attacker: &AttackerContext,
+    defender: &DefenderContext,
+    rules: &CombatRules,
 ) -> Option<HitResult> {
-    let hitbox = attack_hitbox(attacker_pos, attacker_facing, attack, weapon);
-    let hurtbox = defender_hurtbox(defender_pos, defender_hurtbox_local);
+    let hitbox = attack_hitbox(attacker.position, attacker.facing, attack);
+    let hurtbox = defender_hurtbox(defender.position, &defender.hurtbox);
 
     if hitbox.overlaps(&hurtbox) {
-        Some(calculate_hit(
-            attack,
-            weapon,
-            attacker_defense,
-            defender_defense,
-            defender_blocking,
-            attacker_pos,
-            defender_pos,
-            combo_hits,
-            stale_multiplier,
-        ))
+        Some(calculate_hit(attack, attacker, defender, rules))
     } else {
         None
     }
@@ -146,30 +216,59 @@ mod tests {
     use super::*;
     use crate::fighter::FighterData;
     use crate::fighter::FighterId;
+    use crate::fighter::FighterStyle;
     use crate::weapon::WeaponData;
 
     fn test_attack() -> &'static AttackData {
         &FighterData::get(FighterId::Kael).moveset.light_attack
     }
 
-    fn test_weapon() -> &'static WeaponData {
-        WeaponData::get(crate::weapon::WeaponType::Unarmed)
+    const NEUTRAL: StyleModifiers = StyleModifiers {
+        advancing_damage: 1.0,
+        advancing_meter: 1.0,
+        chip_damage: 1.0,
+        block_stamina: 1.0,
+        combo_leniency: 0,
+        special_cancel: false,
+    };
+
+    /// Unarmed attacker at the origin facing right.
+    fn attacker() -> AttackerContext<'static> {
+        AttackerContext {
+            position: Vec3::new(0.0, 0.0, 0.0),
+            facing: Facing::Right,
+            weapon: WeaponData::get(crate::weapon::WeaponType::Unarmed),
+            stats: StatBlock::BASE,
+            style: &NEUTRAL,
+            advancing: false,
+            stale_multiplier: 1.0,
+        }
+    }
+
+    /// Fresh, unblocking defender `x` units to the right.
+    fn defender(x: f32) -> DefenderContext<'static> {
+        DefenderContext {
+            position: Vec3::new(x, 0.0, 0.0),
+            hurtbox: FighterData::get(FighterId::Kael).hurtbox,
+            stats: StatBlock::BASE,
+            style: &NEUTRAL,
+            resistances: &Resistances::NEUTRAL,
+            blocking: false,
+            combo_hits: 0,
+        }
+    }
+
+    fn standard_hit(attacker: &AttackerContext, defender: &DefenderContext) -> HitResult {
+        calculate_hit(test_attack(), attacker, defender, &CombatRules::STANDARD)
     }
 
     #[test]
     fn hit_connects_when_close() {
         let result = check_hit(
-            Vec3::new(0.0, 0.0, 0.0),
-            Facing::Right,
             test_attack(),
-            test_weapon(),
-            1.0,
-            Vec3::new(1.5, 0.0, 0.0),
-            &FighterData::get(FighterId::Kael).hurtbox,
-            1.0,
-            false,
-            0,
-            1.0,
+            &attacker(),
+            &defender(1.5),
+            &CombatRules::STANDARD,
         );
         assert!(result.is_some());
         let hit = result.unwrap();
@@ -180,17 +279,10 @@ mod tests {
     #[test]
     fn hit_misses_when_far() {
         let result = check_hit(
-            Vec3::new(0.0, 0.0, 0.0),
-            Facing::Right,
             test_attack(),
-            test_weapon(),
-            1.0,
-            Vec3::new(10.0, 0.0, 0.0),
-            &FighterData::get(FighterId::Kael).hurtbox,
-            1.0,
-            false,
-            0,
-            1.0,
+            &attacker(),
+            &defender(10.0),
+            &CombatRules::STANDARD,
         );
         assert!(result.is_none());
     }
@@ -198,32 +290,22 @@ mod tests {
     #[test]
     fn blocking_reduces_damage() {
         let unblocked = check_hit(
-            Vec3::new(0.0, 0.0, 0.0),
-            Facing::Right,
             test_attack(),
-            test_weapon(),
-            1.0,
-            Vec3::new(1.5, 0.0, 0.0),
-            &FighterData::get(FighterId::Kael).hurtbox,
-            1.0,
-            false,
-            0,
-            1.0,
+            &attacker(),
+            &defender(1.5),
+            &CombatRules::STANDARD,
         )
         .unwrap();
 
+        let blocking = DefenderContext {
+            blocking: true,
+            ..defender(1.5)
+        };
         let blocked = check_hit(
-            Vec3::new(0.0, 0.0, 0.0),
-            Facing::Right,
             test_attack(),
-            test_weapon(),
-            1.0,
-            Vec3::new(1.5, 0.0, 0.0),
-            &FighterData::get(FighterId::Kael).hurtbox,
-            1.0,
-            true,
-            0,
-            1.0,
+            &attacker(),
+            &blocking,
+            &CombatRules::STANDARD,
         )
         .unwrap();
 
@@ -231,23 +313,16 @@ mod tests {
         assert!(blocked.was_blocked);
         // Blocked damage should be ~20% of unblocked
         let ratio = blocked.damage / unblocked.damage;
-        assert!((ratio - BLOCK_DAMAGE_REDUCTION).abs() < 0.01);
+        assert!((ratio - CombatRules::STANDARD.block_damage).abs() < 0.01);
     }
 
     #[test]
     fn knockback_direction() {
         let hit = check_hit(
-            Vec3::new(0.0, 0.0, 0.0),
-            Facing::Right,
             test_attack(),
-            test_weapon(),
-            1.0,
-            Vec3::new(1.5, 0.0, 0.0),
-            &FighterData::get(FighterId::Kael).hurtbox,
-            1.0,
-            false,
-            0,
-            1.0,
+            &attacker(),
+            &defender(1.5),
+            &CombatRules::STANDARD,
         )
         .unwrap();
 
@@ -260,20 +335,7 @@ mod tests {
         let special = &FighterData::get(FighterId::Kael).moveset.aerial;
         assert!(special.launches);
 
-        let hit = check_hit(
-            Vec3::new(0.0, 0.0, 0.0),
-            Facing::Right,
-            special,
-            test_weapon(),
-            1.0,
-            Vec3::new(1.0, 0.0, 0.0),
-            &FighterData::get(FighterId::Kael).hurtbox,
-            1.0,
-            false,
-            0,
-            1.0,
-        )
-        .unwrap();
+        let hit = check_hit(special, &attacker(), &defender(1.0), &CombatRules::STANDARD).unwrap();
 
         assert!(hit.launches);
         assert!(hit.knockback.y > 0.0);
@@ -281,28 +343,160 @@ mod tests {
 
     #[test]
     fn combo_scaling_reduces_damage() {
-        let fresh_hit = calculate_hit(
-            test_attack(), test_weapon(), 1.0, 1.0, false,
-            Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.5, 0.0, 0.0), 0, 1.0,
-        );
-        let combo_hit = calculate_hit(
-            test_attack(), test_weapon(), 1.0, 1.0, false,
-            Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.5, 0.0, 0.0), 3, 1.0,
+        let fresh_hit = standard_hit(&attacker(), &defender(1.5));
+        let combo_hit = standard_hit(
+            &attacker(),
+            &DefenderContext {
+                combo_hits: 3,
+                ..defender(1.5)
+            },
         );
         assert!(combo_hit.damage < fresh_hit.damage);
         assert!(combo_hit.hitstun_frames < fresh_hit.hitstun_frames);
     }
 
     #[test]
-    fn stale_move_reduces_damage() {
-        let fresh_hit = calculate_hit(
-            test_attack(), test_weapon(), 1.0, 1.0, false,
-            Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.5, 0.0, 0.0), 0, 1.0,
-        );
-        let stale_hit = calculate_hit(
-            test_attack(), test_weapon(), 1.0, 1.0, false,
-            Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.5, 0.0, 0.0), 0, 0.6,
+    fn rules_change_combo_scaling() {
+        let second_hit = |rules: &CombatRules| {
+            let defender = DefenderContext {
+                combo_hits: 1,
+                ..defender(1.5)
+            };
+            calculate_hit(test_attack(), &attacker(), &defender, rules)
+        };
+        // The tournament table doesn't scale until the third hit
+        assert_eq!(second_hit(&CombatRules::TOURNAMENT).breakdown.combo, 1.0);
+        assert!((second_hit(&CombatRules::STANDARD).breakdown.combo - 0.85).abs() < 0.001);
+    }
+
+    #[test]
+    fn hitstop_scales_with_strength() {
+        let moves = &FighterData::get(FighterId::Kael).moveset;
+        assert!(moves.light_attack.hitstop_frames.is_none());
+        assert!(hitstop_frames(&moves.heavy_attack) > hitstop_frames(&moves.light_attack));
+        // Explicit per-attack values win over the formula
+        assert_eq!(
+            hitstop_frames(&moves.super_attack),
+            moves.super_attack.hitstop_frames.unwrap()
         );
+    }
+
+    #[test]
+    fn stale_move_reduces_damage() {
+        let fresh_hit = standard_hit(&attacker(), &defender(1.5));
+        let stale = AttackerContext {
+            stale_multiplier: 0.6,
+            ..attacker()
+        };
+        let stale_hit = standard_hit(&stale, &defender(1.5));
         assert!(stale_hit.damage < fresh_hit.damage);
     }
+
+    #[test]
+    fn aggressive_style_hits_harder_while_advancing() {
+        let style = FighterStyle::Aggressive.modifiers();
+        let hit = |advancing| {
+            standard_hit(
+                &AttackerContext {
+                    style,
+                    advancing,
+                    ..attacker()
+                },
+                &defender(1.5),
+            )
+        };
+        let ratio = hit(true).damage / hit(false).damage;
+        assert!((ratio - style.advancing_damage).abs() < 0.01);
+    }
+
+    #[test]
+    fn defensive_style_takes_less_chip() {
+        let style = FighterStyle::Defensive.modifiers();
+        let blocked = |style| {
+            standard_hit(
+                &attacker(),
+                &DefenderContext {
+                    style,
+                    blocking: true,
+                    ..defender(1.5)
+                },
+            )
+        };
+        let ratio = blocked(style).damage / blocked(&NEUTRAL).damage;
+        assert!((ratio - style.chip_damage).abs() < 0.01);
+    }
+
+    #[test]
+    fn stats_scale_damage_and_knockback() {
+        let hit = |attacker_stats: StatBlock, defender_stats: StatBlock| {
+            standard_hit(
+                &AttackerContext {
+                    stats: attacker_stats,
+                    ..attacker()
+                },
+                &DefenderContext {
+                    stats: defender_stats,
+                    ..defender(1.5)
+                },
+            )
+        };
+        let base = hit(StatBlock::BASE, StatBlock::BASE);
+        let strong = StatBlock {
+            attack: 1.5,
+            ..StatBlock::BASE
+        };
+        let tough = StatBlock {
+            defense: 2.0,
+            knockback_resistance: 0.5,
+            ..StatBlock::BASE
+        };
+
+        assert!((hit(strong, StatBlock::BASE).damage / base.damage - 1.5).abs() < 0.01);
+        let vs_tough = hit(StatBlock::BASE, tough);
+        assert!((vs_tough.damage / base.damage - 0.5).abs() < 0.01);
+        assert!((vs_tough.knockback.x / base.knockback.x - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn resistances_scale_damage_by_type() {
+        let knight = FighterData::get(FighterId::Knight);
+        let sword = AttackerContext {
+            weapon: WeaponData::get(crate::weapon::WeaponType::SwordAndShield),
+            ..attacker()
+        };
+        let hit = |resistances| {
+            standard_hit(
+                &sword,
+                &DefenderContext {
+                    resistances,
+                    ..defender(1.5)
+                },
+            )
+        };
+        let neutral = hit(&Resistances::NEUTRAL);
+        let resisted = hit(&knight.resistances);
+        assert_eq!(resisted.breakdown.damage_type, DamageType::Slash);
+        assert!((resisted.damage / neutral.damage - knight.resistances.slash).abs() < 0.01);
+        assert_eq!(resisted.breakdown.total, resisted.damage);
+    }
+
+    #[test]
+    fn attack_damage_type_overrides_weapon() {
+        let zara = FighterData::get(FighterId::Zara);
+        let magic = AttackerContext {
+            weapon: WeaponData::get(crate::weapon::WeaponType::Magic),
+            ..attacker()
+        };
+        let hit = calculate_hit(
+            &zara.moveset.mid_kick,
+            &magic,
+            &DefenderContext {
+                resistances: &zara.resistances,
+                ..defender(1.5)
+            },
+            &CombatRules::STANDARD,
+        );
+        assert_eq!(hit.breakdown.damage_type, DamageType::Blunt);
+        assert_eq!(hit.breakdown.resistance, zara.resistances.blunt);
+    }
 }
@@ -0,0 +1,108 @@
+const MIN_COMBO_HITS: u32 = 2; // a single hit isn't reported as a combo
+
+/// A run of hits the defender couldn't get out of.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combo {
+    pub hits: u32,
+    pub damage: f32,
+    /// The move behind each hit, in order.
+    pub moves: Vec<&'static str>,
+    /// Combo damage scaling applied to the latest hit.
+    pub scaling: f32,
+    pub start_frame: u32,
+    /// Frame of the latest hit, or the frame the defender got out once ended.
+    pub end_frame: u32,
+}
+
+impl std::fmt::Display for Combo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-hit combo, {:.1} damage in {}f ({})",
+            self.hits,
+            self.damage,
+            self.end_frame - self.start_frame,
+            self.moves.join(", ")
+        )
+    }
+}
+
+/// The combo a defender is currently taking. Hits that land before the defender
+/// can act again add to it; once they can act, the combo is over.
+#[derive(Debug, Clone, Default)]
+pub struct ComboTracker {
+    current: Option<Combo>,
+}
+
+impl ComboTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a landed hit to the combo, starting a new one if needed.
+    pub fn record_hit(&mut self, frame: u32, attack: &'static str, damage: f32, scaling: f32) {
+        let combo = self.current.get_or_insert_with(|| Combo {
+            hits: 0,
+            damage: 0.0,
+            moves: Vec::new(),
+            scaling: 1.0,
+            start_frame: frame,
+            end_frame: frame,
+        });
+        combo.hits += 1;
+        combo.damage += damage;
+        combo.moves.push(attack);
+        combo.scaling = scaling;
+        combo.end_frame = frame;
+    }
+
+    /// Hits taken so far in the current combo.
+    pub fn hits(&self) -> u32 {
+        self.current.as_ref().map_or(0, |c| c.hits)
+    }
+
+    /// The combo in progress, once it's more than a single hit.
+    pub fn current(&self) -> Option<&Combo> {
+        self.current.as_ref().filter(|c| c.hits >= MIN_COMBO_HITS)
+    }
+
+    /// End the combo on `frame`. Returns it if it was more than a single hit.
+    pub fn end(&mut self, frame: u32) -> Option<Combo> {
+        let mut combo = self.current.take().filter(|c| c.hits >= MIN_COMBO_HITS)?;
+        combo.end_frame = frame;
+        Some(combo)
+    }
+
+    pub fn clear(&mut self) {
+        self.current = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hits_add_up_until_the_combo_ends() {
+        let mut tracker = ComboTracker::new();
+        tracker.record_hit(10, "Cross Punch", 3.0, 1.0);
+        tracker.record_hit(20, "Hook", 4.0, 0.85);
+        assert_eq!(tracker.hits(), 2);
+
+        let combo = tracker.end(45).unwrap();
+        assert_eq!(combo.moves, ["Cross Punch", "Hook"]);
+        assert!((combo.damage - 7.0).abs() < 0.001);
+        assert_eq!(combo.scaling, 0.85);
+        assert_eq!((combo.start_frame, combo.end_frame), (10, 45));
+        assert_eq!(tracker.hits(), 0);
+    }
+
+    #[test]
+    fn single_hits_are_not_combos() {
+        let mut tracker = ComboTracker::new();
+        tracker.record_hit(10, "Cross Punch", 3.0, 1.0);
+        assert!(tracker.current().is_none());
+        assert!(tracker.end(30).is_none());
+        assert_eq!(tracker.hits(), 0);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::physics::StageEdge;
+use crate::rules::CombatRules;
+
+const MAX_ROUNDS: u32 = 99;
+const FRAMES_PER_SECOND: u32 = 60;
+
+/// Per-player adjustments for uneven matchups.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Handicap {
+    pub health: f32, // fraction of the starting health this player gets
+    pub damage: f32, // multiplier on damage this player deals
+}
+
+impl Handicap {
+    pub const NONE: Handicap = Handicap {
+        health: 1.0,
+        damage: 1.0,
+    };
+}
+
+impl Default for Handicap {
+    fn default() -> Self {
+        Handicap::NONE
+    }
+}
+
+/// What happens to super meter when a new round starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeterCarryover {
+    /// Meter built in earlier rounds is kept.
+    Keep,
+    /// Everyone starts each round with an empty meter.
+    Reset,
+}
+
+/// How fighters move along the Z axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthMode {
+    /// Fighters are pulled back onto a shared line, so every attack lines up.
+    Aligned,
+    /// Fighters keep their own Z: sidesteps leave the line and only tracking
+    /// attacks follow them.
+    Free,
+}
+
+/// Settings for a whole match: rounds, timers, starting resources, stage and rules.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchConfig {
+    /// Best of this many rounds. Must be odd.
+    pub rounds: u32,
+    /// Seconds per round, or `None` for no time limit.
+    pub round_time: Option<u32>,
+    pub countdown_frames: u32,
+    pub round_over_frames: u32, // pause between the end of a round and the next
+    /// Fraction of max health and stamina fighters start each round with.
+    pub start_health: f32,
+    pub start_stamina: f32,
+    pub handicaps: [Handicap; 2],
+    /// Whether the stage has walls or open edges (ring-out rule).
+    pub stage_edge: StageEdge,
+    /// Whether super meter carries over between rounds.
+    pub meter_carryover: MeterCarryover,
+    /// Whether fighters are kept on one line or move freely in depth.
+    pub depth_mode: DepthMode,
+    pub rules: CombatRules,
+}
+
+impl MatchConfig {
+    pub const STANDARD: MatchConfig = MatchConfig {
+        rounds: 3,
+        round_time: Some(60),
+        countdown_frames: 180, // 3 seconds at 60fps
+        round_over_frames: 120,
+        start_health: 1.0,
+        start_stamina: 1.0,
+        handicaps: [Handicap::NONE; 2],
+        stage_edge: StageEdge::Walled,
+        meter_carryover: MeterCarryover::Keep,
+        depth_mode: DepthMode::Aligned,
+        rules: CombatRules::STANDARD,
+    };
+
+    /// Longest round time `validate` accepts, in seconds.
+    pub const MAX_ROUND_TIME: u32 = 999;
+
+    /// Round wins needed to take the match.
+    pub fn rounds_to_win(&self) -> u32 {
+        self.rounds / 2 + 1
+    }
+
+    /// Length of a round in frames, or `None` for no time limit.
+    pub fn round_frames(&self) -> Option<u32> {
+        self.round_time
+            .map(|seconds| seconds.saturating_mul(FRAMES_PER_SECOND))
+    }
+
+    /// Check the settings make a playable match.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.rounds.is_multiple_of(2) || self.rounds > MAX_ROUNDS {
+            return Err(ConfigError::Rounds(self.rounds));
+        }
+        if let Some(seconds) = self.round_time {
+            if !(1..=Self::MAX_ROUND_TIME).contains(&seconds) {
+                return Err(ConfigError::RoundTime(seconds));
+            }
+        }
+        if !(self.start_health > 0.0 && self.start_health <= 1.0) {
+            return Err(ConfigError::StartHealth(self.start_health));
+        }
+        if !(0.0..=1.0).contains(&self.start_stamina) {
+            return Err(ConfigError::StartStamina(self.start_stamina));
+        }
+        for (player, handicap) in self.handicaps.iter().enumerate() {
+            let valid = handicap.health > 0.0
+                && handicap.health <= 1.0
+                && handicap.damage > 0.0
+                && handicap.damage.is_finite();
+            if !valid {
+                return Err(ConfigError::Handicap(player));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        MatchConfig::STANDARD
+    }
+}
+
+/// Why a `MatchConfig` was rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConfigError {
+    Rounds(u32),
+    RoundTime(u32),
+    StartHealth(f32),
+    StartStamina(f32),
+    /// Player index with an out-of-range handicap.
+    Handicap(usize),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Rounds(rounds) => {
+                write!(f, "rounds must be odd and 1-{}, got {}", MAX_ROUNDS, rounds)
+            }
+            ConfigError::RoundTime(seconds) => write!(
+                f,
+                "round time must be 1-{} seconds, got {}",
+                MatchConfig::MAX_ROUND_TIME,
+                seconds
+            ),
+            ConfigError::StartHealth(pct) => {
+                write!(f, "starting health must be above 0% and at most 100%, got {}", pct)
+            }
+            ConfigError::StartStamina(pct) => {
+                write!(f, "starting stamina must be 0-100%, got {}", pct)
+            }
+            ConfigError::Handicap(player) => write!(
+                f,
+                "P{} handicap out of range: health must be above 0% and at most 100%, damage above 0",
+                player + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best_of_n_needs_a_majority() {
+        assert_eq!(MatchConfig::STANDARD.rounds_to_win(), 2);
+        let config = MatchConfig {
+            rounds: 5,
+            ..MatchConfig::STANDARD
+        };
+        assert_eq!(config.rounds_to_win(), 3);
+        assert_eq!(
+            MatchConfig {
+                rounds: 1,
+                ..config
+            }
+            .rounds_to_win(),
+            1
+        );
+    }
+
+    #[test]
+    fn invalid_settings_are_rejected() {
+        assert!(MatchConfig::STANDARD.validate().is_ok());
+        assert!(MatchConfig {
+            round_time: None,
+            ..MatchConfig::STANDARD
+        }
+        .validate()
+        .is_ok());
+
+        let bad = [
+            MatchConfig {
+                rounds: 4,
+                ..MatchConfig::STANDARD
+            },
+            MatchConfig {
+                rounds: 0,
+                ..MatchConfig::STANDARD
+            },
+            MatchConfig {
+                round_time: Some(0),
+                ..MatchConfig::STANDARD
+            },
+            MatchConfig {
+                round_time: Some(u32::MAX),
+                ..MatchConfig::STANDARD
+            },
+            MatchConfig {
+                start_health: 0.0,
+                ..MatchConfig::STANDARD
+            },
+            MatchConfig {
+                start_stamina: 1.5,
+                ..MatchConfig::STANDARD
+            },
+            MatchConfig {
+                handicaps: [
+                    Handicap::NONE,
+                    Handicap {
+                        health: 1.0,
+                        damage: 0.0,
+                    },
+                ],
+                ..MatchConfig::STANDARD
+            },
+        ];
+        for config in bad {
+            assert!(
+                config.validate().is_err(),
+                "{:?} should be rejected",
+                config
+            );
+        }
+        assert_eq!(bad[3].validate(), Err(ConfigError::RoundTime(u32::MAX)));
+        assert_eq!(bad[6].validate(), Err(ConfigError::Handicap(1)));
+    }
+
+    #[test]
+    fn longest_round_fits_in_frames() {
+        let config = MatchConfig {
+            round_time: Some(MatchConfig::MAX_ROUND_TIME),
+            ..MatchConfig::STANDARD
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.round_frames(),
+            Some(MatchConfig::MAX_ROUND_TIME * 60)
+        );
+        assert_eq!(MatchConfig::STANDARD.round_frames(), Some(3600));
+    }
+
+    #[test]
+    fn round_time_over_the_cap_is_rejected() {
+        for seconds in [MatchConfig::MAX_ROUND_TIME + 1, u32::MAX] {
+            let config = MatchConfig {
+                round_time: Some(seconds),
+                ..MatchConfig::STANDARD
+            };
+            assert_eq!(config.validate(), Err(ConfigError::RoundTime(seconds)));
+        }
+    }
+}
@@ -1,5 +1,9 @@
-use crate::types::{AABB, Vec3};
-use crate::weapon::WeaponType;
+use crate::input::Direction;
+use crate::state_machine::{ActiveAttack, KnockdownKind};
+use crate::stats::{Stat, StatBlock, StatModifier};
+use crate::status::{StatusEffect, StatusKind};
+use crate::types::{Vec3, AABB};
+use crate::weapon::{DamageType, WeaponType};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FighterId {
@@ -39,24 +43,229 @@ pub enum FighterStyle {
     Technical,
 }
 
+impl FighterStyle {
+    /// The passive ruleset this style grants.
+    pub fn modifiers(self) -> &'static StyleModifiers {
+        match self {
+            FighterStyle::Aggressive => &AGGRESSIVE_STYLE,
+            FighterStyle::Defensive => &DEFENSIVE_STYLE,
+            FighterStyle::Technical => &TECHNICAL_STYLE,
+        }
+    }
+}
+
+impl std::fmt::Display for FighterStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FighterStyle::Aggressive => write!(f, "Aggressive"),
+            FighterStyle::Defensive => write!(f, "Defensive"),
+            FighterStyle::Technical => write!(f, "Technical"),
+        }
+    }
+}
+
+/// Passive gameplay modifiers granted by a fighter's style.
+#[derive(Debug, Clone)]
+pub struct StyleModifiers {
+    pub advancing_damage: f32, // damage multiplier for hits landed while pressing forward
+    pub advancing_meter: f32,  // meter gain multiplier for hits landed while pressing forward
+    pub chip_damage: f32,      // multiplier on damage taken through a block
+    pub block_stamina: f32,    // multiplier on stamina drained by blocking
+    pub combo_leniency: u32,   // extra frames allowed between combo inputs
+    pub special_cancel: bool,  // can cancel a connected normal's recovery into a special
+}
+
+// Aggressive: rewarded for pressing forward
+static AGGRESSIVE_STYLE: StyleModifiers = StyleModifiers {
+    advancing_damage: 1.15,
+    advancing_meter: 1.5,
+    chip_damage: 1.0,
+    block_stamina: 1.0,
+    combo_leniency: 0,
+    special_cancel: false,
+};
+
+// Defensive: sturdier guard, less chip and stamina lost while blocking
+static DEFENSIVE_STYLE: StyleModifiers = StyleModifiers {
+    advancing_damage: 1.0,
+    advancing_meter: 1.0,
+    chip_damage: 0.5,
+    block_stamina: 0.6,
+    combo_leniency: 0,
+    special_cancel: false,
+};
+
+// Technical: looser combo timing and special cancels
+static TECHNICAL_STYLE: StyleModifiers = StyleModifiers {
+    advancing_damage: 1.0,
+    advancing_meter: 1.0,
+    chip_damage: 1.0,
+    block_stamina: 1.0,
+    combo_leniency: 20,
+    special_cancel: true,
+};
+
+/// What kind of attack this is, matched against projectile and throw invincibility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackClass {
+    Strike,
+    Projectile,
+    Throw,
+}
+
+impl std::fmt::Display for AttackClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttackClass::Strike => write!(f, "Strike"),
+            AttackClass::Projectile => write!(f, "Projectile"),
+            AttackClass::Throw => write!(f, "Throw"),
+        }
+    }
+}
+
+/// Which of the attacker's sides an attack follows a sidestepping opponent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tracking {
+    /// Only hits straight ahead; a sidestep either way avoids it.
+    None,
+    Left,
+    Right,
+    /// Sweeps around both sides.
+    Full,
+}
+
+impl Tracking {
+    /// Whether the attack follows an opponent standing off to `side` (Left or Right).
+    pub fn covers(&self, side: Direction) -> bool {
+        matches!(
+            (self, side),
+            (Tracking::Full, _)
+                | (Tracking::Left, Direction::Left)
+                | (Tracking::Right, Direction::Right)
+        )
+    }
+}
+
+impl std::fmt::Display for Tracking {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tracking::None => write!(f, "None"),
+            Tracking::Left => write!(f, "Left"),
+            Tracking::Right => write!(f, "Right"),
+            Tracking::Full => write!(f, "Full"),
+        }
+    }
+}
+
+/// An inclusive range of frames counted from the start of an attack (frame 1 = first startup frame).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl FrameRange {
+    pub const fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+
+    pub fn contains(&self, frame: u32) -> bool {
+        (self.start..=self.end).contains(&frame)
+    }
+}
+
+impl std::fmt::Display for FrameRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Frame data for a forward dash, backdash or sidestep.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DashData {
+    pub speed: f32,
+    pub frames: u32,
+    pub stamina_cost: f32,
+    pub invuln: Option<FrameRange>, // frames (from 1) that attacks pass through
+}
+
+/// Armor that absorbs up to `hits` hits without flinching during `frames`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Armor {
+    pub frames: FrameRange,
+    pub hits: u32,
+}
+
 #[derive(Debug, Clone)]
 pub struct AttackData {
     pub name: &'static str,
-    pub anim: &'static str,           // animation filename without extension
-    pub damage_multiplier: f32,        // applied on top of weapon base damage
+    pub anim: &'static str,     // animation filename without extension
+    pub damage_multiplier: f32, // applied on top of weapon base damage, per hit
     pub startup_frames: u32,
     pub active_frames: u32,
     pub recovery_frames: u32,
+    pub stamina_cost: f32,                   // paid when the attack starts
+    pub movement: &'static [AttackMovement], // how the attacker steps during the attack
     pub knockback_force: f32,
-    pub hitbox_offset: Vec3,           // relative to fighter position + facing
+    pub hitbox_offset: Vec3, // relative to fighter position + facing
     pub hitbox_half_extents: Vec3,
-    pub launches: bool,                // sends opponent airborne
+    pub launches: bool,                   // sends opponent airborne
+    pub knockdown: Option<KnockdownKind>, // None = the hit only causes hitstun
+    pub hitstun_frames: Option<u32>,      // None = scale with knockback
+    pub damage_type: Option<DamageType>,  // None = the weapon's damage type
+    pub hits: u32,                        // times the attack can land
+    pub rehit_interval: u32,              // frames between hits of a multi-hit attack
+    pub class: AttackClass,
+    pub tracking: Tracking,         // sides it follows a sidestep to
+    pub invuln: Option<FrameRange>, // can't be hit by anything
+    pub projectile_invuln: Option<FrameRange>, // passes through projectiles
+    pub throw_invuln: Option<FrameRange>, // can't be thrown
+    pub armor: Option<Armor>,       // absorbs hits without flinching
+    pub disarms: bool,              // knocks the opponent's weapon out of their hands
+    pub on_hit_buff: Option<StatModifier>, // applied to the attacker when the hit lands
+    pub on_hit_debuff: Option<StatModifier>, // applied to the defender when the hit lands
+    pub on_hit_status: Option<StatusEffect>, // status inflicted on the defender
+    pub hitstop_frames: Option<u32>, // freeze on impact; None = scale with knockback
+    pub juggle_points: u32,         // cost against the defender's juggle budget
+    pub hits_airborne: bool,        // can connect with a launched opponent
+    pub hits_otg: bool,             // can connect with a knocked-down opponent
 }
 
 impl AttackData {
     pub fn total_frames(&self) -> u32 {
         self.startup_frames + self.active_frames + self.recovery_frames
     }
+
+    /// The attacker's speed along their facing on `frame` of the attack.
+    pub fn movement_at(&self, frame: u32) -> f32 {
+        self.movement
+            .iter()
+            .find(|m| m.frames.contains(frame))
+            .map_or(0.0, |m| m.speed)
+    }
+}
+
+/// A stretch of an attack during which the attacker moves, e.g. a lunge or a hop back.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttackMovement {
+    pub frames: FrameRange,
+    pub speed: f32, // along the attacker's facing; negative moves them back
+}
+
+impl AttackMovement {
+    pub const fn forward(start: u32, end: u32, speed: f32) -> Self {
+        Self {
+            frames: FrameRange::new(start, end),
+            speed,
+        }
+    }
+
+    pub const fn back(start: u32, end: u32, speed: f32) -> Self {
+        Self {
+            frames: FrameRange::new(start, end),
+            speed: -speed,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -71,6 +280,49 @@ pub struct MoveSet {
     pub super_attack: AttackData,
 }
 
+/// A variant of a normal, used when its button is pressed with a direction held
+/// (e.g. forward + light).
+#[derive(Debug, Clone)]
+pub struct CommandNormal {
+    pub direction: Direction,
+    pub button: ActiveAttack, // the plain normal this replaces: Light, Heavy, MidKick or LowKick
+    pub attack: AttackData,
+}
+
+/// An alternate stance with its own idle pose and transitions. Its moves replace
+/// the base moveset's in the same slots while the stance is held, so they can
+/// only be used from the stance.
+#[derive(Debug, Clone)]
+pub struct Stance {
+    pub name: &'static str,
+    pub idle_anim: &'static str,
+    pub enter_anim: &'static str,
+    pub enter_frames: u32,
+    pub exit_anim: &'static str,
+    pub exit_frames: u32,
+    pub moves: &'static [StanceMove],
+}
+
+impl Stance {
+    /// The stance's own move for a slot, if it has one.
+    pub fn move_for(&self, slot: ActiveAttack) -> Option<&AttackData> {
+        let slot = match slot {
+            ActiveAttack::EnhancedSpecial => ActiveAttack::Special,
+            other => other,
+        };
+        self.moves
+            .iter()
+            .find(|m| m.slot == slot)
+            .map(|m| &m.attack)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StanceMove {
+    pub slot: ActiveAttack,
+    pub attack: AttackData,
+}
+
 /// Maps game states to animation filenames (without extension).
 #[derive(Debug, Clone)]
 pub struct AnimationSet {
@@ -86,6 +338,33 @@ pub struct AnimationSet {
     pub getting_up: &'static str,
     pub death: &'static str,
     pub sweep_fall: &'static str,
+    pub backdash: &'static str,
+    pub sidestep_left: &'static str,
+    pub sidestep_right: &'static str,
+}
+
+/// Multipliers on damage taken per damage type (below 1.0 resists, above 1.0 is a weakness).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resistances {
+    pub blunt: f32,
+    pub slash: f32,
+    pub arcane: f32,
+}
+
+impl Resistances {
+    pub const NEUTRAL: Resistances = Resistances {
+        blunt: 1.0,
+        slash: 1.0,
+        arcane: 1.0,
+    };
+
+    pub fn get(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Blunt => self.blunt,
+            DamageType::Slash => self.slash,
+            DamageType::Arcane => self.arcane,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -95,12 +374,17 @@ pub struct FighterData {
     pub max_health: f32,
     pub max_stamina: f32,
     pub move_speed: f32,
-    pub dash_speed: f32,
-    pub dash_frames: u32,
-    pub defense: f32,          // damage reduction multiplier (lower = less damage taken)
+    pub dash: DashData,
+    pub backdash: DashData,
+    pub sidestep: DashData, // evades strikes and projectiles, but not throws
+    pub tech_roll_distance: f32, // ground covered by a wake-up tech roll
+    pub stats: StatBlock,   // base attack/defense/speed/knockback resistance
     pub default_weapon: WeaponType,
+    pub resistances: Resistances,
     pub moveset: MoveSet,
-    pub hurtbox: AABB,         // body hurtbox relative to position (origin at feet)
+    pub command_normals: &'static [CommandNormal],
+    pub stances: &'static [Stance], // alternate stances, entered in order with the stance button
+    pub hurtbox: AABB,              // body hurtbox relative to position (origin at feet)
     pub animations: AnimationSet,
 }
 
@@ -125,10 +409,33 @@ static KAEL: FighterData = FighterData {
     max_health: 95.0,
     max_stamina: 110.0,
     move_speed: 6.0,
-    dash_speed: 14.0,
-    dash_frames: 9,
-    defense: 1.0,
+    dash: DashData {
+        speed: 14.0,
+        frames: 9,
+        stamina_cost: 20.0,
+        invuln: Some(FrameRange::new(1, 9)),
+    },
+    backdash: DashData {
+        speed: 11.0,
+        frames: 13,
+        stamina_cost: 15.0,
+        invuln: Some(FrameRange::new(1, 6)),
+    },
+    sidestep: DashData {
+        speed: 8.0,
+        frames: 12,
+        stamina_cost: 12.0,
+        invuln: Some(FrameRange::new(2, 9)),
+    },
+    tech_roll_distance: 3.0,
+    stats: StatBlock {
+        attack: 1.0,
+        defense: 1.0,
+        speed: 1.0,
+        knockback_resistance: 0.0,
+    },
     default_weapon: WeaponType::Unarmed,
+    resistances: Resistances::NEUTRAL,
     moveset: MoveSet {
         light_attack: AttackData {
             name: "Cross Punch",
@@ -137,10 +444,31 @@ static KAEL: FighterData = FighterData {
             startup_frames: 6,
             active_frames: 4,
             recovery_frames: 14,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 3.0)],
             knockback_force: 2.5,
             hitbox_offset: Vec3::new(0.9, 0.8, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.3, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: true,
+            hits_otg: false,
         },
         heavy_attack: AttackData {
             name: "Hook",
@@ -149,10 +477,31 @@ static KAEL: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 6,
             recovery_frames: 22,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 5.0,
             hitbox_offset: Vec3::new(0.8, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.4, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         special_attack: AttackData {
             name: "Illegal Elbow",
@@ -161,10 +510,31 @@ static KAEL: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 8,
             recovery_frames: 28,
+            stamina_cost: 30.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 6.0,
             hitbox_offset: Vec3::new(0.8, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.4, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: true,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         mid_kick: AttackData {
             name: "Roundhouse Kick",
@@ -173,10 +543,31 @@ static KAEL: FighterData = FighterData {
             startup_frames: 10,
             active_frames: 5,
             recovery_frames: 18,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 5, 3.5)],
             knockback_force: 4.0,
             hitbox_offset: Vec3::new(1.0, 0.6, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.3, 0.4),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         low_kick: AttackData {
             name: "Leg Sweep",
@@ -185,10 +576,31 @@ static KAEL: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 5,
             recovery_frames: 16,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 2.5)],
             knockback_force: 3.0,
             hitbox_offset: Vec3::new(0.9, 0.2, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.3, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Sweep),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: false,
+            hits_otg: true,
         },
         aerial: AttackData {
             name: "Flip Kick",
@@ -197,10 +609,31 @@ static KAEL: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 8,
             recovery_frames: 30,
+            stamina_cost: 15.0,
+            movement: &[],
             knockback_force: 7.0,
             hitbox_offset: Vec3::new(0.7, 1.2, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.7, 0.3),
             launches: true,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: Some(FrameRange::new(1, 14)),
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         combo_finisher: AttackData {
             name: "Knee Jab",
@@ -209,10 +642,31 @@ static KAEL: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 10,
             recovery_frames: 28,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 5.0)],
             knockback_force: 9.0,
             hitbox_offset: Vec3::new(0.8, 0.7, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.4, 0.3),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(10),
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         super_attack: AttackData {
             name: "Capoeira",
@@ -221,12 +675,115 @@ static KAEL: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 14,
             recovery_frames: 36,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 8, 4.0)],
             knockback_force: 12.0,
             hitbox_offset: Vec3::new(0.9, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.8, 0.6, 0.5),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: Some(FrameRange::new(1, 12)),
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: Some(StatModifier {
+                stat: Stat::Attack,
+                amount: 0.2,
+                frames: 300,
+                source: "Capoeira",
+            }),
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(14),
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
     },
+    command_normals: &[
+        CommandNormal {
+            direction: Direction::Forward,
+            button: ActiveAttack::Light,
+            attack: AttackData {
+                name: "Lunging Cross",
+                anim: "Cross Punch",
+                damage_multiplier: 0.8,
+                startup_frames: 9,
+                active_frames: 4,
+                recovery_frames: 16,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::forward(1, 8, 8.0)],
+                knockback_force: 3.5,
+                hitbox_offset: Vec3::new(1.3, 1.0, 0.0),
+                hitbox_half_extents: Vec3::new(0.6, 0.3, 0.3),
+                launches: false,
+                knockdown: None,
+                hitstun_frames: None,
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::None,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 1,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+        CommandNormal {
+            direction: Direction::Back,
+            button: ActiveAttack::Heavy,
+            attack: AttackData {
+                name: "Rising Elbow",
+                anim: "Illegal Elbow Punch",
+                damage_multiplier: 1.6,
+                startup_frames: 10,
+                active_frames: 5,
+                recovery_frames: 24,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::back(1, 5, 3.0)],
+                knockback_force: 4.0,
+                hitbox_offset: Vec3::new(0.7, 1.4, 0.0),
+                hitbox_half_extents: Vec3::new(0.5, 0.5, 0.3),
+                launches: true,
+                knockdown: None,
+                hitstun_frames: None,
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::None,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 2,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+    ],
+    stances: &[],
     hurtbox: AABB {
         min: Vec3::new(-0.35, 0.0, -0.3),
         max: Vec3::new(0.35, 1.75, 0.3),
@@ -244,6 +801,9 @@ static KAEL: FighterData = FighterData {
         getting_up: "Standing Up",
         death: "Falling Back Death",
         sweep_fall: "Sweep Fall",
+        backdash: "Standing Dodge Backward",
+        sidestep_left: "Standing Dodge Left",
+        sidestep_right: "Standing Dodge Right",
     },
 };
 
@@ -256,10 +816,37 @@ static KNIGHT: FighterData = FighterData {
     max_health: 115.0,
     max_stamina: 90.0,
     move_speed: 4.0,
-    dash_speed: 10.0,
-    dash_frames: 12,
-    defense: 0.85,
+    dash: DashData {
+        speed: 10.0,
+        frames: 12,
+        stamina_cost: 20.0,
+        invuln: Some(FrameRange::new(1, 12)),
+    },
+    backdash: DashData {
+        speed: 9.0,
+        frames: 14,
+        stamina_cost: 20.0,
+        invuln: Some(FrameRange::new(1, 4)),
+    },
+    sidestep: DashData {
+        speed: 7.0,
+        frames: 14,
+        stamina_cost: 15.0,
+        invuln: Some(FrameRange::new(3, 9)),
+    },
+    tech_roll_distance: 2.2,
+    stats: StatBlock {
+        attack: 1.0,
+        defense: 1.18,
+        speed: 1.0,
+        knockback_resistance: 0.15,
+    },
     default_weapon: WeaponType::SwordAndShield,
+    resistances: Resistances {
+        blunt: 1.0,
+        slash: 0.75,
+        arcane: 1.1,
+    },
     moveset: MoveSet {
         light_attack: AttackData {
             name: "Sword Slash",
@@ -268,10 +855,31 @@ static KNIGHT: FighterData = FighterData {
             startup_frames: 6,
             active_frames: 4,
             recovery_frames: 14,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 3.0)],
             knockback_force: 4.0,
             hitbox_offset: Vec3::new(1.2, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.5, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: true,
+            hits_otg: false,
         },
         heavy_attack: AttackData {
             name: "Heavy Slash",
@@ -280,10 +888,38 @@ static KNIGHT: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 6,
             recovery_frames: 22,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 6.0,
             hitbox_offset: Vec3::new(1.1, 1.0, 0.0),
             hitbox_half_extents: Vec3::new(0.8, 0.5, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: Some(Armor {
+                frames: FrameRange::new(1, 14),
+                hits: 1,
+            }),
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: Some(StatusEffect {
+                kind: StatusKind::Stagger,
+                frames: 40,
+                potency: 0.0,
+            }),
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         special_attack: AttackData {
             name: "Shield Strike",
@@ -292,10 +928,36 @@ static KNIGHT: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 8,
             recovery_frames: 28,
+            stamina_cost: 30.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 8.0,
             hitbox_offset: Vec3::new(1.0, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.5, 0.4),
             launches: false,
+            knockdown: None,
+            hitstun_frames: Some(28),
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: Some(FrameRange::new(1, 16)),
+            throw_invuln: None,
+            armor: None,
+            disarms: true,
+            on_hit_buff: None,
+            on_hit_debuff: Some(StatModifier {
+                stat: Stat::Defense,
+                amount: -0.15,
+                frames: 180,
+                source: "Shield Strike",
+            }),
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         mid_kick: AttackData {
             name: "MMA Kick",
@@ -304,10 +966,31 @@ static KNIGHT: FighterData = FighterData {
             startup_frames: 10,
             active_frames: 5,
             recovery_frames: 18,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 5, 3.5)],
             knockback_force: 4.5,
             hitbox_offset: Vec3::new(1.0, 0.6, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.3, 0.4),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         low_kick: AttackData {
             name: "Groin Kick",
@@ -316,10 +999,31 @@ static KNIGHT: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 5,
             recovery_frames: 16,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 2.5)],
             knockback_force: 3.5,
             hitbox_offset: Vec3::new(0.9, 0.3, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.3, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Sweep),
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: false,
+            hits_otg: true,
         },
         aerial: AttackData {
             name: "Drop Kick",
@@ -328,22 +1032,64 @@ static KNIGHT: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 8,
             recovery_frames: 30,
+            stamina_cost: 15.0,
+            movement: &[],
             knockback_force: 7.5,
             hitbox_offset: Vec3::new(0.8, 1.1, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.6, 0.4),
             launches: true,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: Some(FrameRange::new(1, 14)),
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         combo_finisher: AttackData {
             name: "Two Hand Combo",
             anim: "Two Hand Sword Combo",
-            damage_multiplier: 5.5,
+            damage_multiplier: 2.75,
             startup_frames: 8,
             active_frames: 10,
             recovery_frames: 28,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 5.0)],
             knockback_force: 10.0,
             hitbox_offset: Vec3::new(1.1, 1.0, 0.0),
             hitbox_half_extents: Vec3::new(0.8, 0.6, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 2,
+            rehit_interval: 6,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(10),
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         super_attack: AttackData {
             name: "Double Kick Kip Up",
@@ -352,12 +1098,110 @@ static KNIGHT: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 14,
             recovery_frames: 36,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 8, 4.0)],
             knockback_force: 13.0,
             hitbox_offset: Vec3::new(0.8, 1.2, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.8, 0.5),
             launches: true,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: Some(FrameRange::new(1, 12)),
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(14),
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
     },
+    command_normals: &[
+        CommandNormal {
+            direction: Direction::Forward,
+            button: ActiveAttack::Heavy,
+            attack: AttackData {
+                name: "Lunging Thrust",
+                anim: "Sword And Shield Slash (3)",
+                damage_multiplier: 2.2,
+                startup_frames: 18,
+                active_frames: 5,
+                recovery_frames: 26,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::forward(1, 8, 8.0)],
+                knockback_force: 6.5,
+                hitbox_offset: Vec3::new(1.6, 1.0, 0.0),
+                hitbox_half_extents: Vec3::new(0.9, 0.3, 0.4),
+                launches: false,
+                knockdown: None,
+                hitstun_frames: None,
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::None,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 2,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+        CommandNormal {
+            direction: Direction::Back,
+            button: ActiveAttack::Light,
+            attack: AttackData {
+                name: "Guarded Slash",
+                anim: "Sword And Shield Slash",
+                damage_multiplier: 0.7,
+                startup_frames: 10,
+                active_frames: 4,
+                recovery_frames: 18,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::back(1, 5, 3.0)],
+                knockback_force: 3.0,
+                hitbox_offset: Vec3::new(1.1, 1.0, 0.0),
+                hitbox_half_extents: Vec3::new(0.7, 0.4, 0.4),
+                launches: false,
+                knockdown: None,
+                hitstun_frames: Some(10),
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::None,
+                invuln: None,
+                projectile_invuln: Some(FrameRange::new(1, 10)),
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 1,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+    ],
+    stances: &[],
     hurtbox: AABB {
         min: Vec3::new(-0.45, 0.0, -0.3),
         max: Vec3::new(0.45, 1.9, 0.3),
@@ -375,6 +1219,9 @@ static KNIGHT: FighterData = FighterData {
         getting_up: "Knocked Out",
         death: "Falling Back Death",
         sweep_fall: "Sweep Fall",
+        backdash: "Standing Dodge Backward",
+        sidestep_left: "Standing Dodge Left",
+        sidestep_right: "Standing Dodge Right",
     },
 };
 
@@ -387,10 +1234,37 @@ static ZARA: FighterData = FighterData {
     max_health: 90.0,
     max_stamina: 120.0,
     move_speed: 5.5,
-    dash_speed: 13.0,
-    dash_frames: 8,
-    defense: 1.05,
+    dash: DashData {
+        speed: 13.0,
+        frames: 8,
+        stamina_cost: 20.0,
+        invuln: Some(FrameRange::new(1, 8)),
+    },
+    backdash: DashData {
+        speed: 12.0,
+        frames: 12,
+        stamina_cost: 15.0,
+        invuln: Some(FrameRange::new(1, 6)),
+    },
+    sidestep: DashData {
+        speed: 9.0,
+        frames: 11,
+        stamina_cost: 10.0,
+        invuln: Some(FrameRange::new(2, 9)),
+    },
+    tech_roll_distance: 3.2,
+    stats: StatBlock {
+        attack: 1.0,
+        defense: 0.95,
+        speed: 1.0,
+        knockback_resistance: 0.0,
+    },
     default_weapon: WeaponType::Magic,
+    resistances: Resistances {
+        blunt: 1.2,
+        slash: 1.0,
+        arcane: 0.8,
+    },
     moveset: MoveSet {
         light_attack: AttackData {
             name: "Punching",
@@ -399,10 +1273,31 @@ static ZARA: FighterData = FighterData {
             startup_frames: 6,
             active_frames: 4,
             recovery_frames: 14,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 3.0)],
             knockback_force: 2.5,
             hitbox_offset: Vec3::new(0.8, 0.8, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.3, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: true,
+            hits_otg: false,
         },
         heavy_attack: AttackData {
             name: "Elbow Strike",
@@ -411,10 +1306,31 @@ static ZARA: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 6,
             recovery_frames: 22,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 5.0,
             hitbox_offset: Vec3::new(0.7, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.4, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: true,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         special_attack: AttackData {
             name: "Magic Bolt",
@@ -423,10 +1339,36 @@ static ZARA: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 8,
             recovery_frames: 28,
+            stamina_cost: 30.0,
+            movement: &[],
             knockback_force: 7.0,
             hitbox_offset: Vec3::new(1.2, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.8, 0.5, 0.4),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Projectile,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: Some(StatModifier {
+                stat: Stat::Speed,
+                amount: -0.2,
+                frames: 120,
+                source: "Magic Bolt",
+            }),
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         mid_kick: AttackData {
             name: "MMA Kick",
@@ -435,10 +1377,31 @@ static ZARA: FighterData = FighterData {
             startup_frames: 10,
             active_frames: 5,
             recovery_frames: 18,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 5, 3.5)],
             knockback_force: 4.0,
             hitbox_offset: Vec3::new(1.0, 0.6, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.3, 0.4),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         low_kick: AttackData {
             name: "Leg Sweep",
@@ -447,10 +1410,31 @@ static ZARA: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 5,
             recovery_frames: 16,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 2.5)],
             knockback_force: 3.0,
             hitbox_offset: Vec3::new(0.9, 0.2, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.3, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Sweep),
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: false,
+            hits_otg: true,
         },
         aerial: AttackData {
             name: "Flip Kick",
@@ -459,10 +1443,31 @@ static ZARA: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 8,
             recovery_frames: 30,
+            stamina_cost: 15.0,
+            movement: &[],
             knockback_force: 7.0,
             hitbox_offset: Vec3::new(0.7, 1.2, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.7, 0.3),
             launches: true,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: Some(FrameRange::new(1, 14)),
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         combo_finisher: AttackData {
             name: "Meia Lua",
@@ -471,24 +1476,150 @@ static ZARA: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 10,
             recovery_frames: 28,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 5.0)],
             knockback_force: 9.5,
             hitbox_offset: Vec3::new(0.9, 0.8, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.5, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: Some(DamageType::Blunt),
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(10),
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         super_attack: AttackData {
             name: "Magic Storm",
             anim: "Standing 2H Magic Area Attack 02",
-            damage_multiplier: 10.0,
+            damage_multiplier: 3.4,
             startup_frames: 12,
             active_frames: 14,
             recovery_frames: 36,
+            stamina_cost: 0.0,
+            movement: &[],
             knockback_force: 13.0,
             hitbox_offset: Vec3::new(0.8, 1.0, 0.0),
             hitbox_half_extents: Vec3::new(1.0, 0.8, 0.6),
             launches: true,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 3,
+            rehit_interval: 5,
+            class: AttackClass::Projectile,
+            tracking: Tracking::Full,
+            invuln: Some(FrameRange::new(1, 12)),
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: Some(StatusEffect {
+                kind: StatusKind::Burn,
+                frames: 180,
+                potency: 1.0,
+            }),
+            hitstop_frames: Some(14),
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
     },
+    command_normals: &[
+        CommandNormal {
+            direction: Direction::Forward,
+            button: ActiveAttack::MidKick,
+            attack: AttackData {
+                name: "Push Kick",
+                anim: "Mma Kick (1)",
+                damage_multiplier: 1.3,
+                startup_frames: 12,
+                active_frames: 4,
+                recovery_frames: 20,
+                stamina_cost: 0.0,
+                movement: &[
+                    AttackMovement::forward(1, 6, 2.0),
+                    AttackMovement::back(17, 24, 2.5),
+                ],
+                knockback_force: 6.0,
+                hitbox_offset: Vec3::new(1.1, 0.9, 0.0),
+                hitbox_half_extents: Vec3::new(0.6, 0.4, 0.3),
+                launches: false,
+                knockdown: None,
+                hitstun_frames: Some(22),
+                damage_type: Some(DamageType::Blunt),
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::None,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 1,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+        CommandNormal {
+            direction: Direction::Left,
+            button: ActiveAttack::Heavy,
+            attack: AttackData {
+                name: "Spinning Elbow",
+                anim: "Elbow Punching",
+                damage_multiplier: 1.4,
+                startup_frames: 12,
+                active_frames: 5,
+                recovery_frames: 20,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::forward(1, 4, 3.0)],
+                knockback_force: 4.0,
+                hitbox_offset: Vec3::new(0.8, 1.0, 0.0),
+                hitbox_half_extents: Vec3::new(0.6, 0.4, 0.6),
+                launches: false,
+                knockdown: None,
+                hitstun_frames: None,
+                damage_type: Some(DamageType::Blunt),
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::Full,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 1,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+    ],
+    stances: &[],
     hurtbox: AABB {
         min: Vec3::new(-0.4, 0.0, -0.3),
         max: Vec3::new(0.4, 1.8, 0.3),
@@ -506,6 +1637,9 @@ static ZARA: FighterData = FighterData {
         getting_up: "Getting Up",
         death: "Falling Back Death",
         sweep_fall: "Sweep Fall",
+        backdash: "Standing Dodge Backward",
+        sidestep_left: "Standing Dodge Left",
+        sidestep_right: "Standing Dodge Right",
     },
 };
 
@@ -518,10 +1652,37 @@ static MAGNUS: FighterData = FighterData {
     max_health: 105.0,
     max_stamina: 100.0,
     move_speed: 5.0,
-    dash_speed: 12.0,
-    dash_frames: 10,
-    defense: 0.95,
+    dash: DashData {
+        speed: 12.0,
+        frames: 10,
+        stamina_cost: 20.0,
+        invuln: Some(FrameRange::new(1, 10)),
+    },
+    backdash: DashData {
+        speed: 9.5,
+        frames: 14,
+        stamina_cost: 20.0,
+        invuln: Some(FrameRange::new(1, 4)),
+    },
+    sidestep: DashData {
+        speed: 7.0,
+        frames: 14,
+        stamina_cost: 15.0,
+        invuln: Some(FrameRange::new(3, 9)),
+    },
+    tech_roll_distance: 2.6,
+    stats: StatBlock {
+        attack: 1.0,
+        defense: 1.05,
+        speed: 1.0,
+        knockback_resistance: 0.25,
+    },
     default_weapon: WeaponType::Unarmed,
+    resistances: Resistances {
+        blunt: 0.85,
+        slash: 1.1,
+        arcane: 1.0,
+    },
     moveset: MoveSet {
         light_attack: AttackData {
             name: "Punching",
@@ -530,10 +1691,31 @@ static MAGNUS: FighterData = FighterData {
             startup_frames: 6,
             active_frames: 4,
             recovery_frames: 14,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 3.0)],
             knockback_force: 3.0,
             hitbox_offset: Vec3::new(0.9, 0.8, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.3, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: true,
+            hits_otg: false,
         },
         heavy_attack: AttackData {
             name: "Mutant Punch",
@@ -542,22 +1724,72 @@ static MAGNUS: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 6,
             recovery_frames: 22,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 6.5,
             hitbox_offset: Vec3::new(0.8, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.4, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: Some(Armor {
+                frames: FrameRange::new(1, 14),
+                hits: 1,
+            }),
+            disarms: true,
+            on_hit_buff: None,
+            on_hit_debuff: Some(StatModifier {
+                stat: Stat::Defense,
+                amount: -0.2,
+                frames: 180,
+                source: "Mutant Punch",
+            }),
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         special_attack: AttackData {
             name: "Flying Knee Combo",
             anim: "Flying Knee Punch Combo",
-            damage_multiplier: 3.5,
+            damage_multiplier: 1.75,
             startup_frames: 12,
             active_frames: 8,
             recovery_frames: 28,
+            stamina_cost: 30.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 8.0,
             hitbox_offset: Vec3::new(0.7, 1.2, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.7, 0.3),
             launches: true,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 2,
+            rehit_interval: 5,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         mid_kick: AttackData {
             name: "Martelo",
@@ -566,10 +1798,31 @@ static MAGNUS: FighterData = FighterData {
             startup_frames: 10,
             active_frames: 5,
             recovery_frames: 18,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 5, 3.5)],
             knockback_force: 4.5,
             hitbox_offset: Vec3::new(1.0, 0.7, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.4, 0.4),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         low_kick: AttackData {
             name: "Rasteira",
@@ -578,10 +1831,36 @@ static MAGNUS: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 5,
             recovery_frames: 16,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 2.5)],
             knockback_force: 3.5,
             hitbox_offset: Vec3::new(0.9, 0.2, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.3, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Sweep),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: Some(StatModifier {
+                stat: Stat::Speed,
+                amount: -0.3,
+                frames: 120,
+                source: "Rasteira",
+            }),
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: false,
+            hits_otg: true,
         },
         aerial: AttackData {
             name: "Mutant Jump Attack",
@@ -590,10 +1869,31 @@ static MAGNUS: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 8,
             recovery_frames: 30,
+            stamina_cost: 15.0,
+            movement: &[],
             knockback_force: 7.5,
             hitbox_offset: Vec3::new(0.7, 1.3, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.7, 0.4),
             launches: true,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: Some(FrameRange::new(1, 14)),
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         combo_finisher: AttackData {
             name: "Queshada",
@@ -602,10 +1902,34 @@ static MAGNUS: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 10,
             recovery_frames: 28,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 5.0)],
             knockback_force: 10.0,
             hitbox_offset: Vec3::new(0.9, 0.8, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.5, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: Some(Armor {
+                frames: FrameRange::new(1, 8),
+                hits: 2,
+            }),
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(10),
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         super_attack: AttackData {
             name: "Meia Lua",
@@ -614,12 +1938,113 @@ static MAGNUS: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 14,
             recovery_frames: 36,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 8, 4.0)],
             knockback_force: 12.0,
             hitbox_offset: Vec3::new(1.0, 1.0, 0.0),
             hitbox_half_extents: Vec3::new(0.8, 0.6, 0.5),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: Some(FrameRange::new(1, 12)),
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(14),
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
     },
+    command_normals: &[
+        CommandNormal {
+            direction: Direction::Forward,
+            button: ActiveAttack::Heavy,
+            attack: AttackData {
+                name: "Mutant Charge",
+                anim: "mutant punch",
+                damage_multiplier: 2.6,
+                startup_frames: 20,
+                active_frames: 6,
+                recovery_frames: 28,
+                stamina_cost: 15.0,
+                movement: &[AttackMovement::forward(1, 12, 8.0)],
+                knockback_force: 8.0,
+                hitbox_offset: Vec3::new(1.2, 1.0, 0.0),
+                hitbox_half_extents: Vec3::new(0.7, 0.5, 0.3),
+                launches: false,
+                knockdown: Some(KnockdownKind::Soft),
+                hitstun_frames: None,
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::None,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: Some(Armor {
+                    frames: FrameRange::new(6, 20),
+                    hits: 1,
+                }),
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 2,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+        CommandNormal {
+            direction: Direction::Back,
+            button: ActiveAttack::LowKick,
+            attack: AttackData {
+                name: "Rasteira Reversa",
+                anim: "rasteira 2",
+                damage_multiplier: 1.2,
+                startup_frames: 10,
+                active_frames: 5,
+                recovery_frames: 22,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::back(1, 6, 4.0)],
+                knockback_force: 4.0,
+                hitbox_offset: Vec3::new(0.7, 0.3, 0.0),
+                hitbox_half_extents: Vec3::new(0.7, 0.3, 0.3),
+                launches: false,
+                knockdown: Some(KnockdownKind::Sweep),
+                hitstun_frames: None,
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::Full,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 1,
+                hits_airborne: false,
+                hits_otg: true,
+            },
+        },
+    ],
+    stances: &[],
     hurtbox: AABB {
         min: Vec3::new(-0.4, 0.0, -0.3),
         max: Vec3::new(0.4, 1.85, 0.3),
@@ -637,6 +2062,9 @@ static MAGNUS: FighterData = FighterData {
         getting_up: "Standing Up",
         death: "Falling Back Death",
         sweep_fall: "Leg Sweep",
+        backdash: "Standing Dodge Backward",
+        sidestep_left: "Standing Dodge Left",
+        sidestep_right: "Standing Dodge Right",
     },
 };
 
@@ -649,10 +2077,37 @@ static ORIN: FighterData = FighterData {
     max_health: 100.0,
     max_stamina: 105.0,
     move_speed: 5.5,
-    dash_speed: 13.0,
-    dash_frames: 9,
-    defense: 1.0,
+    dash: DashData {
+        speed: 13.0,
+        frames: 9,
+        stamina_cost: 20.0,
+        invuln: Some(FrameRange::new(1, 9)),
+    },
+    backdash: DashData {
+        speed: 11.0,
+        frames: 12,
+        stamina_cost: 12.0,
+        invuln: Some(FrameRange::new(1, 7)),
+    },
+    sidestep: DashData {
+        speed: 9.5,
+        frames: 11,
+        stamina_cost: 10.0,
+        invuln: Some(FrameRange::new(2, 10)),
+    },
+    tech_roll_distance: 3.5,
+    stats: StatBlock {
+        attack: 1.0,
+        defense: 1.0,
+        speed: 1.0,
+        knockback_resistance: 0.05,
+    },
     default_weapon: WeaponType::Unarmed,
+    resistances: Resistances {
+        blunt: 1.0,
+        slash: 1.0,
+        arcane: 1.1,
+    },
     moveset: MoveSet {
         light_attack: AttackData {
             name: "Punching",
@@ -661,10 +2116,31 @@ static ORIN: FighterData = FighterData {
             startup_frames: 6,
             active_frames: 4,
             recovery_frames: 14,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 3.0)],
             knockback_force: 2.5,
             hitbox_offset: Vec3::new(0.9, 0.8, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.3, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: true,
+            hits_otg: false,
         },
         heavy_attack: AttackData {
             name: "Hook",
@@ -673,10 +2149,31 @@ static ORIN: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 6,
             recovery_frames: 22,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 4.0)],
             knockback_force: 5.5,
             hitbox_offset: Vec3::new(0.8, 0.9, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.4, 0.3),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         special_attack: AttackData {
             name: "Backflip Uppercut",
@@ -685,10 +2182,34 @@ static ORIN: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 8,
             recovery_frames: 28,
+            stamina_cost: 30.0,
+            movement: &[
+                AttackMovement::forward(1, 4, 3.0),
+                AttackMovement::back(21, 30, 5.0),
+            ],
             knockback_force: 7.5,
             hitbox_offset: Vec3::new(0.6, 1.3, 0.0),
             hitbox_half_extents: Vec3::new(0.5, 0.8, 0.3),
             launches: true,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         mid_kick: AttackData {
             name: "Martelo",
@@ -697,10 +2218,31 @@ static ORIN: FighterData = FighterData {
             startup_frames: 10,
             active_frames: 5,
             recovery_frames: 18,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 5, 3.5)],
             knockback_force: 4.0,
             hitbox_offset: Vec3::new(1.0, 0.6, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.3, 0.4),
             launches: false,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Right,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
         low_kick: AttackData {
             name: "Rasteira",
@@ -709,10 +2251,31 @@ static ORIN: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 5,
             recovery_frames: 16,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 4, 2.5)],
             knockback_force: 3.0,
             hitbox_offset: Vec3::new(0.9, 0.2, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.3, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Sweep),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 1,
+            hits_airborne: false,
+            hits_otg: true,
         },
         aerial: AttackData {
             name: "Armada",
@@ -721,10 +2284,31 @@ static ORIN: FighterData = FighterData {
             startup_frames: 14,
             active_frames: 8,
             recovery_frames: 30,
+            stamina_cost: 15.0,
+            movement: &[],
             knockback_force: 6.5,
             hitbox_offset: Vec3::new(0.8, 1.1, 0.0),
             hitbox_half_extents: Vec3::new(0.6, 0.6, 0.4),
             launches: true,
+            knockdown: None,
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::None,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: Some(FrameRange::new(1, 14)),
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: None,
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         combo_finisher: AttackData {
             name: "Chapa Giratoria",
@@ -733,10 +2317,36 @@ static ORIN: FighterData = FighterData {
             startup_frames: 8,
             active_frames: 10,
             recovery_frames: 28,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 6, 5.0)],
             knockback_force: 9.0,
             hitbox_offset: Vec3::new(0.9, 0.8, 0.0),
             hitbox_half_extents: Vec3::new(0.7, 0.5, 0.4),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Left,
+            invuln: None,
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: true,
+            on_hit_buff: Some(StatModifier {
+                stat: Stat::Speed,
+                amount: 0.15,
+                frames: 180,
+                source: "Chapa Giratoria",
+            }),
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(10),
+            juggle_points: 3,
+            hits_airborne: true,
+            hits_otg: false,
         },
         super_attack: AttackData {
             name: "Meia Lua",
@@ -745,12 +2355,228 @@ static ORIN: FighterData = FighterData {
             startup_frames: 12,
             active_frames: 14,
             recovery_frames: 36,
+            stamina_cost: 0.0,
+            movement: &[AttackMovement::forward(1, 8, 4.0)],
             knockback_force: 12.0,
             hitbox_offset: Vec3::new(1.0, 1.0, 0.0),
             hitbox_half_extents: Vec3::new(0.8, 0.6, 0.5),
             launches: false,
+            knockdown: Some(KnockdownKind::Hard),
+            hitstun_frames: None,
+            damage_type: None,
+            hits: 1,
+            rehit_interval: 0,
+            class: AttackClass::Strike,
+            tracking: Tracking::Full,
+            invuln: Some(FrameRange::new(1, 12)),
+            projectile_invuln: None,
+            throw_invuln: None,
+            armor: None,
+            disarms: false,
+            on_hit_buff: None,
+            on_hit_debuff: None,
+            on_hit_status: None,
+            hitstop_frames: Some(14),
+            juggle_points: 2,
+            hits_airborne: true,
+            hits_otg: false,
         },
     },
+    // Ginga: the swaying capoeira base, with kicks that only flow out of it
+    command_normals: &[
+        CommandNormal {
+            direction: Direction::Forward,
+            button: ActiveAttack::MidKick,
+            attack: AttackData {
+                name: "Martelo Rodado",
+                anim: "martelo 3",
+                damage_multiplier: 1.5,
+                startup_frames: 13,
+                active_frames: 5,
+                recovery_frames: 20,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::forward(1, 4, 3.0)],
+                knockback_force: 5.0,
+                hitbox_offset: Vec3::new(1.2, 1.1, 0.0),
+                hitbox_half_extents: Vec3::new(0.6, 0.4, 0.3),
+                launches: false,
+                knockdown: None,
+                hitstun_frames: None,
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::None,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 2,
+                hits_airborne: true,
+                hits_otg: false,
+            },
+        },
+        CommandNormal {
+            direction: Direction::Back,
+            button: ActiveAttack::LowKick,
+            attack: AttackData {
+                name: "Negativa Sweep",
+                anim: "rasteira 1",
+                damage_multiplier: 1.0,
+                startup_frames: 9,
+                active_frames: 5,
+                recovery_frames: 20,
+                stamina_cost: 0.0,
+                movement: &[AttackMovement::back(1, 6, 4.0)],
+                knockback_force: 3.5,
+                hitbox_offset: Vec3::new(0.9, 0.3, 0.0),
+                hitbox_half_extents: Vec3::new(0.7, 0.3, 0.3),
+                launches: false,
+                knockdown: Some(KnockdownKind::Sweep),
+                hitstun_frames: None,
+                damage_type: None,
+                hits: 1,
+                rehit_interval: 0,
+                class: AttackClass::Strike,
+                tracking: Tracking::Full,
+                invuln: None,
+                projectile_invuln: None,
+                throw_invuln: None,
+                armor: None,
+                disarms: false,
+                on_hit_buff: None,
+                on_hit_debuff: None,
+                on_hit_status: None,
+                hitstop_frames: None,
+                juggle_points: 1,
+                hits_airborne: false,
+                hits_otg: true,
+            },
+        },
+    ],
+    stances: &[Stance {
+        name: "Ginga",
+        idle_anim: "ginga",
+        enter_anim: "ginga",
+        enter_frames: 10,
+        exit_anim: "Idle",
+        exit_frames: 8,
+        moves: &[
+            StanceMove {
+                slot: ActiveAttack::Light,
+                attack: AttackData {
+                    name: "Meia Lua de Frente",
+                    anim: "meia lua de frente",
+                    damage_multiplier: 0.9,
+                    startup_frames: 8,
+                    active_frames: 4,
+                    recovery_frames: 16,
+                    stamina_cost: 0.0,
+                    movement: &[AttackMovement::forward(1, 4, 3.0)],
+                    knockback_force: 3.5,
+                    hitbox_offset: Vec3::new(1.0, 0.9, 0.0),
+                    hitbox_half_extents: Vec3::new(0.6, 0.4, 0.3),
+                    launches: false,
+                    knockdown: None,
+                    hitstun_frames: None,
+                    damage_type: None,
+                    hits: 1,
+                    rehit_interval: 0,
+                    class: AttackClass::Strike,
+                    tracking: Tracking::Right,
+                    invuln: None,
+                    projectile_invuln: None,
+                    throw_invuln: None,
+                    armor: None,
+                    disarms: false,
+                    on_hit_buff: None,
+                    on_hit_debuff: None,
+                    on_hit_status: None,
+                    hitstop_frames: None,
+                    juggle_points: 1,
+                    hits_airborne: true,
+                    hits_otg: false,
+                },
+            },
+            StanceMove {
+                slot: ActiveAttack::Heavy,
+                attack: AttackData {
+                    name: "Queixada",
+                    anim: "queixada",
+                    damage_multiplier: 2.4,
+                    startup_frames: 16,
+                    active_frames: 5,
+                    recovery_frames: 24,
+                    stamina_cost: 0.0,
+                    movement: &[AttackMovement::forward(1, 4, 3.0)],
+                    knockback_force: 7.0,
+                    hitbox_offset: Vec3::new(1.1, 1.1, 0.0),
+                    hitbox_half_extents: Vec3::new(0.7, 0.4, 0.3),
+                    launches: false,
+                    knockdown: None,
+                    hitstun_frames: None,
+                    damage_type: None,
+                    hits: 1,
+                    rehit_interval: 0,
+                    class: AttackClass::Strike,
+                    tracking: Tracking::None,
+                    invuln: None,
+                    projectile_invuln: None,
+                    throw_invuln: Some(FrameRange::new(1, 12)),
+                    armor: None,
+                    disarms: false,
+                    on_hit_buff: None,
+                    on_hit_debuff: None,
+                    on_hit_status: None,
+                    hitstop_frames: None,
+                    juggle_points: 2,
+                    hits_airborne: true,
+                    hits_otg: false,
+                },
+            },
+            StanceMove {
+                slot: ActiveAttack::LowKick,
+                attack: AttackData {
+                    name: "Vingativa",
+                    anim: "vingativa",
+                    damage_multiplier: 1.6,
+                    startup_frames: 12,
+                    active_frames: 5,
+                    recovery_frames: 22,
+                    stamina_cost: 0.0,
+                    movement: &[AttackMovement::forward(1, 4, 3.0)],
+                    knockback_force: 4.5,
+                    hitbox_offset: Vec3::new(0.8, 0.3, 0.0),
+                    hitbox_half_extents: Vec3::new(0.6, 0.3, 0.3),
+                    launches: true,
+                    knockdown: Some(KnockdownKind::Hard),
+                    hitstun_frames: None,
+                    damage_type: None,
+                    hits: 1,
+                    rehit_interval: 0,
+                    class: AttackClass::Strike,
+                    tracking: Tracking::None,
+                    invuln: None,
+                    projectile_invuln: None,
+                    throw_invuln: None,
+                    armor: None,
+                    disarms: false,
+                    on_hit_buff: None,
+                    on_hit_debuff: None,
+                    on_hit_status: None,
+                    hitstop_frames: None,
+                    juggle_points: 2,
+                    hits_airborne: false,
+                    hits_otg: false,
+                },
+            },
+        ],
+    }],
     hurtbox: AABB {
         min: Vec3::new(-0.35, 0.0, -0.3),
         max: Vec3::new(0.35, 1.75, 0.3),
@@ -768,5 +2594,8 @@ static ORIN: FighterData = FighterData {
         getting_up: "Standing Up",
         death: "Fallen Idle",
         sweep_fall: "Sweep Fall",
+        backdash: "Standing Dodge Backward",
+        sidestep_left: "Standing Dodge Left",
+        sidestep_right: "Standing Dodge Right",
     },
 };
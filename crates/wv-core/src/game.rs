@@ -1,51 +1,227 @@
-use crate::combat;
-use crate::fighter::{AttackData, FighterData, FighterId};
-use crate::input::{ComboType, InputBuffer, InputState};
-use crate::physics::PhysicsBody;
-use crate::state_machine::{ActiveAttack, FighterState, StateMachine};
-use crate::types::{Facing, Vec3};
-use crate::weapon::WeaponData;
+use crate::behavior::{self, FighterBehavior, InputOverride};
+use crate::combat::{self, AttackerContext, DamageBreakdown, DefenderContext};
+use crate::combo::{Combo, ComboTracker};
+use crate::config::{ConfigError, DepthMode, MatchConfig, MeterCarryover};
+use crate::fighter::{AttackClass, AttackData, FighterData, FighterId, FrameRange, Stance};
+use crate::input::{ComboType, Direction, InputAction, InputBuffer, InputState};
+use crate::physics::{PhysicsBody, StageEdge};
+use crate::stale::StaleQueue;
+use crate::state_machine::{
+    ActiveAttack, DashKind, FighterState, KnockdownKind, StateMachine, WakeUp, TECH_ROLL_FRAMES,
+};
+use crate::stats::{StatBlock, StatModifiers};
+use crate::status::{StatusEffects, StatusKind};
+use crate::types::{Facing, Vec3, AABB};
+use crate::weapon::{WeaponData, WeaponItem, WeaponType};
 
 const DT: f32 = 1.0 / 60.0;
-const ROUND_TIME_SECONDS: f32 = 60.0;
-const ROUND_TIME_FRAMES: u32 = (ROUND_TIME_SECONDS * 60.0) as u32;
-const ROUNDS_TO_WIN: u32 = 2;
 const STAMINA_REGEN_RATE: f32 = 0.3; // per frame
-const DASH_STAMINA_COST: f32 = 20.0;
-const SPECIAL_STAMINA_COST: f32 = 30.0;
-const AERIAL_STAMINA_COST: f32 = 15.0;
-const SUPER_STAMINA_COST: f32 = 50.0;
-const ATTACK_LUNGE: f32 = 3.5; // forward impulse when starting any attack
-const COMBO_BREAKER_HITS: u32 = 5; // auto-break free after this many consecutive hits
+const EXHAUSTED_COOLDOWN_FRAMES: u32 = 120; // no stamina regen for 2 seconds after running dry
+const EXHAUSTED_RECOVERY_STAMINA: f32 = 0.25; // fraction of max stamina restored on recovering
+const EXHAUSTED_MOVE_SPEED: f32 = 0.6; // movement speed multiplier while exhausted
+const EXHAUSTED_STARTUP: f32 = 1.25; // attack startup multiplier while exhausted
+const EXHAUSTED_HITSTUN: f32 = 1.3; // hitstun taken multiplier while exhausted
+const BLOCK_STAMINA_COST: f32 = 5.0; // flat stamina drained per blocked hit
+const BLOCK_STAMINA_PER_DAMAGE: f32 = 5.0; // extra drain per point of chip damage
+const GUARD_BREAK_STUN_FRAMES: u32 = 30; // stun when a blocked hit empties stamina
+const DISARM_FLING: Vec3 = Vec3::new(4.0, 6.0, 0.0); // impulse on a knocked-away weapon
+const PICKUP_RANGE: f32 = 1.0; // how close a fighter must be to grab a weapon
+const SWEPT_HURTBOX_HEIGHT: f32 = 0.35; // fraction of standing height while swept
+const OFF_LINE_TOLERANCE: f32 = 0.05; // Z offset below which fighters count as in line
+const TRACKING_REACH: f32 = 2.0; // how far off the line a tracking attack can follow
+pub const METER_PER_BAR: f32 = 100.0;
+pub const METER_BARS: u32 = 3;
+const SUPER_METER_COST: f32 = METER_PER_BAR;
+const ENHANCED_SPECIAL_METER_COST: f32 = METER_PER_BAR * 0.5;
+const ENHANCED_SPECIAL_DAMAGE: f32 = 1.5; // damage multiplier over the plain special
+const METER_GAIN_DEALT: f32 = 2.0; // meter per point of damage dealt
+const METER_GAIN_TAKEN: f32 = 1.5; // meter per point of damage taken
+const METER_GAIN_BLOCKED: f32 = 4.0; // flat meter for blocking a hit
+const JUGGLE_BUDGET: u32 = 6; // juggle points a fighter can absorb in one combo before breaking free
+const JUGGLE_POP_VELOCITY: f32 = 4.0; // upward pop when hit again while airborne
+const OTG_KNOCKDOWN_FRAMES: u32 = 20; // downtime added by an off-the-ground hit
+const LOOP_BREAK_KNOCKDOWN_FRAMES: u32 = 25; // knockdown when a grounded loop spends the budget
+const LOOP_BREAK_PUSH: f32 = 8.0; // knockback pushing the fighters apart on a loop break
+const MULTI_HIT_KNOCKBACK: f32 = 0.25; // knockback kept by non-final hits of a multi-hit attack
+const MULTI_HIT_STUN_PADDING: u32 = 4; // extra stun so the next hit of a multi-hit connects
+const CLASH_PUSHBACK: f32 = 4.0; // knockback on both fighters when attacks clash
+const CLASH_HITSTOP_FRAMES: u32 = 6;
 const FIGHTER_BODY_RADIUS: f32 = 0.45; // half-width of a fighter's body for collision
 const MIN_FIGHTER_DISTANCE: f32 = FIGHTER_BODY_RADIUS * 2.0; // minimum separation on X-axis
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GamePhase {
     FighterSelect,
-    Countdown,  // 3-2-1-FIGHT
+    Countdown, // 3-2-1-FIGHT
     Fighting,
     RoundOver,
     MatchOver,
 }
 
+/// Why a round ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundEndReason {
+    Knockout,
+    TimeUp,
+    RingOut,
+}
+
+impl std::fmt::Display for RoundEndReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoundEndReason::Knockout => write!(f, "KO"),
+            RoundEndReason::TimeUp => write!(f, "Time"),
+            RoundEndReason::RingOut => write!(f, "Ring Out"),
+        }
+    }
+}
+
+/// Outcome of a finished round. `winner` is `None` for a draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundResult {
+    pub winner: Option<usize>,
+    pub reason: RoundEndReason,
+}
+
+/// Notable things that happened during a tick, for frontends to react to.
+/// Cleared at the start of every `GameState::tick`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    /// A fighter was knocked over an open stage edge.
+    RingOut {
+        player: usize,
+    },
+    RoundOver(RoundResult),
+    /// A fighter ran out of stamina and is exhausted.
+    Exhausted {
+        player: usize,
+    },
+    /// A fighter's exhaustion cooldown ended.
+    Recovered {
+        player: usize,
+    },
+    /// An attack connected, with how its damage was worked out.
+    Hit {
+        attacker: usize,
+        attack: &'static str,
+        breakdown: DamageBreakdown,
+        blocked: bool,
+    },
+    /// A combo of two or more hits ended: the defender got out or the round ended.
+    ComboEnded {
+        attacker: usize,
+        combo: Combo,
+    },
+    /// Both fighters hit each other on the same frame.
+    Trade,
+    /// Both attacks' hitboxes met: the attacks cancel out and the fighters bounce apart.
+    Clash,
+    /// A blocked hit emptied the defender's stamina and broke their guard.
+    GuardBreak {
+        player: usize,
+    },
+    /// A fighter's weapon was knocked away onto the stage.
+    Disarmed {
+        player: usize,
+        weapon: WeaponType,
+    },
+    /// A fighter picked a weapon up from the stage.
+    WeaponPickedUp {
+        player: usize,
+        weapon: WeaponType,
+    },
+}
+
+impl std::fmt::Display for GameEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameEvent::RingOut { player } => write!(f, "P{} ring out", player + 1),
+            GameEvent::Exhausted { player } => write!(f, "P{} exhausted", player + 1),
+            GameEvent::Recovered { player } => write!(f, "P{} recovered", player + 1),
+            GameEvent::Hit {
+                attacker,
+                attack,
+                breakdown,
+                blocked,
+            } => write!(
+                f,
+                "P{} {}: {}{}",
+                attacker + 1,
+                attack,
+                breakdown,
+                if *blocked { " blocked" } else { "" }
+            ),
+            GameEvent::ComboEnded { attacker, combo } => write!(f, "P{} {}", attacker + 1, combo),
+            GameEvent::Trade => write!(f, "Trade"),
+            GameEvent::Clash => write!(f, "Clash"),
+            GameEvent::GuardBreak { player } => write!(f, "P{} guard break", player + 1),
+            GameEvent::Disarmed { player, weapon } => {
+                write!(f, "P{} lost their {}", player + 1, weapon)
+            }
+            GameEvent::WeaponPickedUp { player, weapon } => {
+                write!(f, "P{} picked up {}", player + 1, weapon)
+            }
+            GameEvent::RoundOver(result) => match result.winner {
+                Some(w) => write!(f, "P{} wins the round ({})", w + 1, result.reason),
+                None => write!(f, "Round drawn ({})", result.reason),
+            },
+        }
+    }
+}
+
+/// A hit detected this frame, waiting to be applied.
+struct PendingHit {
+    attacker_idx: usize,
+    attack_data: AttackData,
+    hit: combat::HitResult,
+    attacker_pos: Vec3,
+    attacker_advancing: bool,
+    defender_state: FighterState,
+    is_blocking: bool,
+    armored: bool,
+    final_hit: bool,
+    first_hit: bool,
+    combo_hits: u32,
+}
+
 #[derive(Debug, Clone)]
 pub struct Fighter {
     pub data: &'static FighterData,
     pub weapon: &'static WeaponData,
+    /// Whether the fighter's weapon was knocked away and they haven't picked one up since.
+    pub disarmed: bool,
     pub state_machine: StateMachine,
     pub physics: PhysicsBody,
     pub input_buffer: InputBuffer,
     pub health: f32,
     pub stamina: f32,
+    /// Super meter, built through combat and spent on supers and enhanced specials.
+    pub meter: f32,
+    /// Frames left of exhaustion after stamina ran out (0 = not exhausted).
+    pub exhausted_timer: u32,
     pub facing: Facing,
     pub round_wins: u32,
-    /// How many consecutive hits this fighter has taken without recovering.
-    pub combo_hits_taken: u32,
-    /// Tracks the last attack used by this fighter (for stale move detection).
-    pub last_attack_used: Option<ActiveAttack>,
-    /// How many times the same attack has been used consecutively.
-    pub attack_repeat_count: u32,
+    /// The combo this fighter is taking: hits since they were last able to act.
+    pub combo: ComboTracker,
+    /// Juggle points spent on this fighter since they were last able to act.
+    pub juggle_points_taken: u32,
+    /// Remaining hitstop frames: while non-zero the state machine and physics are frozen.
+    pub hitstop: u32,
+    /// Moves this fighter landed recently, which deal less damage when reused.
+    pub stale: StaleQueue,
+    /// Stale multiplier of the current attack, fixed when it started so a
+    /// multi-hit attack isn't staled by its own earlier hits.
+    pub attack_stale: f32,
+    /// Whether the fighter was pressing toward the opponent when they last acted.
+    pub advancing: bool,
+    /// Timed buffs and debuffs on top of the fighter's base stats.
+    pub modifiers: StatModifiers,
+    /// Status effects such as burn, stagger and stun.
+    pub status: StatusEffects,
+    /// Hits soaked by the current attack's armor.
+    pub armor_hits_absorbed: u32,
+    /// The fighter's own mechanic, such as Knight's shield or Zara's mana.
+    pub behavior: Box<dyn FighterBehavior>,
 }
 
 impl Fighter {
@@ -55,16 +231,26 @@ impl Fighter {
         Self {
             data,
             weapon,
+            disarmed: false,
             state_machine: StateMachine::new(),
             physics: PhysicsBody::new(position),
-            input_buffer: InputBuffer::new(),
+            input_buffer: InputBuffer::with_leniency(data.style.modifiers().combo_leniency),
             health: data.max_health,
             stamina: data.max_stamina,
+            meter: 0.0,
+            exhausted_timer: 0,
             facing,
             round_wins: 0,
-            combo_hits_taken: 0,
-            last_attack_used: None,
-            attack_repeat_count: 0,
+            combo: ComboTracker::new(),
+            juggle_points_taken: 0,
+            hitstop: 0,
+            stale: StaleQueue::new(),
+            attack_stale: 1.0,
+            advancing: false,
+            modifiers: StatModifiers::new(),
+            status: StatusEffects::new(),
+            armor_hits_absorbed: 0,
+            behavior: behavior::for_fighter(id),
         }
     }
 
@@ -72,6 +258,11 @@ impl Fighter {
         self.health > 0.0
     }
 
+    /// Whether the fighter is free to act: in a neutral state and not stunned.
+    pub fn can_act(&self) -> bool {
+        self.state_machine.can_act() && !self.status.has(StatusKind::Stun)
+    }
+
     pub fn health_pct(&self) -> f32 {
         self.health / self.data.max_health
     }
@@ -80,33 +271,216 @@ impl Fighter {
         self.stamina / self.data.max_stamina
     }
 
-    /// Track stale move usage when starting an attack.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted_timer > 0
+    }
+
+    /// Base stats with active buffs and debuffs applied.
+    pub fn effective_stats(&self) -> StatBlock {
+        self.modifiers.effective(&self.data.stats)
+    }
+
+    /// Startup frames for an attack after weapon speed, speed stat, exhaustion
+    /// and the fighter's own mechanic.
+    fn startup_frames(&self, attack: ActiveAttack) -> u32 {
+        let speed = self.weapon.attack_speed * self.effective_stats().speed;
+        let mut startup = self.get_attack_data(attack).startup_frames as f32 / speed
+            * self.behavior.startup_multiplier(attack);
+        if self.is_exhausted() {
+            startup *= EXHAUSTED_STARTUP;
+        }
+        startup as u32
+    }
+
+    /// Whether the fighter has had their weapon knocked away and is still empty-handed.
+    pub fn is_disarmed(&self) -> bool {
+        self.disarmed
+    }
+
+    /// Let go of the current weapon, falling back to unarmed stats.
+    /// Returns the dropped weapon, or `None` if already unarmed.
+    pub fn drop_weapon(&mut self) -> Option<WeaponType> {
+        let dropped = self.weapon.weapon_type;
+        if dropped == WeaponType::Unarmed {
+            return None;
+        }
+        self.weapon = WeaponData::get(WeaponType::Unarmed);
+        self.disarmed = true;
+        Some(dropped)
+    }
+
+    /// Take up a weapon found on the stage.
+    pub fn pick_up(&mut self, weapon: WeaponType) {
+        self.weapon = WeaponData::get(weapon);
+        self.disarmed = false;
+    }
+
+    /// Number of full meter bars available.
+    pub fn meter_bars(&self) -> u32 {
+        (self.meter / METER_PER_BAR) as u32
+    }
+
+    pub fn gain_meter(&mut self, amount: f32) {
+        self.meter = (self.meter + amount).min(METER_PER_BAR * METER_BARS as f32);
+    }
+
+    /// Record pressed attack buttons in the input buffer for combo detection.
+    fn buffer_attack_inputs(&mut self, input: &InputState) {
+        if input.light_attack {
+            self.input_buffer.push(InputAction::LightAttack);
+        }
+        if input.heavy_attack {
+            self.input_buffer.push(InputAction::HeavyAttack);
+        }
+        if input.special {
+            self.input_buffer.push(InputAction::Special);
+        }
+    }
+
+    /// Bookkeeping when starting an attack: behavior hooks, armor and staleness.
     fn track_attack_used(&mut self, attack: ActiveAttack) {
-        if self.last_attack_used == Some(attack) {
-            self.attack_repeat_count += 1;
+        self.behavior.on_attack_started(attack);
+        // Every new attack starts with fresh armor
+        self.armor_hits_absorbed = 0;
+        self.attack_stale = self.stale.multiplier(self.get_attack_data(attack).name);
+    }
+
+    /// Whether `attack` is allowed to connect with this fighter in their current state.
+    /// Airborne and downed fighters can only be hit by attacks flagged for it, and
+    /// only while the juggle budget lasts.
+    /// `tracked` means the attack follows this fighter to the side they're on, which
+    /// catches them out of a sidestep.
+    pub fn can_be_hit_by(&self, attack: &AttackData, tracked: bool) -> bool {
+        let caught_sidestepping = tracked && self.is_sidestepping();
+        if !caught_sidestepping && self.is_invulnerable_to(attack.class) {
+            return false;
+        }
+        let within_budget = self.juggle_points_taken + attack.juggle_points <= JUGGLE_BUDGET;
+        match self.state_machine.state {
+            FighterState::Airborne => attack.hits_airborne && within_budget,
+            FighterState::Knockdown | FighterState::Swept => {
+                attack.hits_otg && within_budget && self.is_alive()
+            }
+            _ => self.state_machine.is_vulnerable(),
+        }
+    }
+
+    /// Body hurtbox relative to position. A swept fighter lies low, so only
+    /// low-reaching attacks connect.
+    pub fn hurtbox(&self) -> AABB {
+        let mut hurtbox = self.data.hurtbox;
+        if self.state_machine.state == FighterState::Swept {
+            hurtbox.max.y *= SWEPT_HURTBOX_HEIGHT;
+        }
+        hurtbox
+    }
+
+    pub fn is_sidestepping(&self) -> bool {
+        self.state_machine.state == FighterState::Dashing
+            && self.state_machine.dash_kind == DashKind::Side
+    }
+
+    /// Which of this fighter's sides `position` is off to, or None if it's in line.
+    pub fn side_of(&self, position: Vec3) -> Option<Direction> {
+        let offset = (position.z - self.physics.position.z) * self.facing.sign();
+        if offset.abs() < OFF_LINE_TOLERANCE {
+            None
+        } else if offset > 0.0 {
+            Some(Direction::Right)
         } else {
-            self.last_attack_used = Some(attack);
-            self.attack_repeat_count = 0;
+            Some(Direction::Left)
+        }
+    }
+
+    /// The attack in progress and how many frames into it the fighter is.
+    fn current_attack_frame(&self) -> Option<(&AttackData, u32)> {
+        if self.state_machine.state != FighterState::Attacking {
+            return None;
+        }
+        let attack = self.state_machine.attack?;
+        Some((
+            self.get_attack_data(attack),
+            self.state_machine.frame_counter,
+        ))
+    }
+
+    /// Whether the current attack's or dash's invincibility frames cover an incoming
+    /// `class` of attack.
+    pub fn is_invulnerable_to(&self, class: AttackClass) -> bool {
+        if self.state_machine.state == FighterState::Dashing {
+            let dash = match self.state_machine.dash_kind {
+                DashKind::Forward => &self.data.dash,
+                DashKind::Back => &self.data.backdash,
+                // Throws catch a sidestepping fighter
+                DashKind::Side if class == AttackClass::Throw => return false,
+                DashKind::Side => &self.data.sidestep,
+            };
+            return dash
+                .invuln
+                .is_some_and(|r| r.contains(self.state_machine.frame_counter));
         }
+        let Some((attack, frame)) = self.current_attack_frame() else {
+            return false;
+        };
+        let covers = |range: Option<FrameRange>| range.is_some_and(|r| r.contains(frame));
+        covers(attack.invuln)
+            || (class == AttackClass::Projectile && covers(attack.projectile_invuln))
+            || (class == AttackClass::Throw && covers(attack.throw_invuln))
+    }
+
+    /// Whether the current attack's armor is active and can soak another hit.
+    pub fn has_armor(&self) -> bool {
+        self.current_attack_frame()
+            .and_then(|(attack, frame)| attack.armor.map(|armor| (armor, frame)))
+            .is_some_and(|(armor, frame)| {
+                armor.frames.contains(frame) && self.armor_hits_absorbed < armor.hits
+            })
     }
 
-    /// Stale move damage multiplier: repeating the same attack reduces damage.
-    pub fn stale_move_multiplier(&self) -> f32 {
-        // Each repeat reduces damage by 20%, down to 40% minimum
-        (1.0 - self.attack_repeat_count as f32 * 0.2).max(0.4)
+    /// The stance the fighter is in, or `None` for the base stance.
+    pub fn stance(&self) -> Option<&'static Stance> {
+        self.state_machine
+            .stance
+            .and_then(|i| self.data.stances.get(i))
     }
 
-    fn get_attack_data(&self, attack: ActiveAttack) -> &AttackData {
+    /// The attack in `attack`'s slot, taking the current stance's moves first.
+    pub fn get_attack_data(&self, attack: ActiveAttack) -> &AttackData {
+        if let Some(stance_move) = self.stance().and_then(|s| s.move_for(attack)) {
+            return stance_move;
+        }
         match attack {
             ActiveAttack::Light => &self.data.moveset.light_attack,
             ActiveAttack::Heavy => &self.data.moveset.heavy_attack,
-            ActiveAttack::Special => &self.data.moveset.special_attack,
+            ActiveAttack::Special | ActiveAttack::EnhancedSpecial => {
+                &self.data.moveset.special_attack
+            }
             ActiveAttack::MidKick => &self.data.moveset.mid_kick,
             ActiveAttack::LowKick => &self.data.moveset.low_kick,
             ActiveAttack::Aerial => &self.data.moveset.aerial,
             ActiveAttack::ComboFinisher => &self.data.moveset.combo_finisher,
             ActiveAttack::Super => &self.data.moveset.super_attack,
+            ActiveAttack::Command(i) => &self.data.command_normals[i].attack,
+        }
+    }
+
+    /// The attack a normal `button` turns into with the directions held: the first
+    /// matching command normal in `Direction::PRIORITY` order, otherwise the plain
+    /// normal. Stances use their own moves instead of command normals.
+    pub fn resolve_normal(&self, button: ActiveAttack, input: &InputState) -> ActiveAttack {
+        if self.stance().is_some() {
+            return button;
         }
+        Direction::PRIORITY
+            .into_iter()
+            .filter(|&direction| input.holds(direction, self.facing))
+            .find_map(|direction| {
+                self.data
+                    .command_normals
+                    .iter()
+                    .position(|c| c.direction == direction && c.button == button)
+            })
+            .map_or(button, ActiveAttack::Command)
     }
 
     /// Returns the animation name the frontend should play for the current state.
@@ -117,7 +491,9 @@ impl Fighter {
         }
 
         match self.state_machine.state {
-            FighterState::Idle => self.data.animations.idle,
+            FighterState::Idle => self
+                .stance()
+                .map_or(self.data.animations.idle, |s| s.idle_anim),
             FighterState::Moving => {
                 let fs = self.facing.sign();
                 let fv = self.physics.velocity.x * fs; // positive = forward
@@ -145,25 +521,58 @@ impl Fighter {
                 }
             }
             FighterState::Blocking => self.data.animations.block,
-            FighterState::Dashing => self.data.animations.run,
+            FighterState::Dashing => match self.state_machine.dash_kind {
+                DashKind::Forward => self.data.animations.run,
+                DashKind::Back => self.data.animations.backdash,
+                DashKind::Side => {
+                    let model_left =
+                        (self.physics.velocity.z < 0.0) == (self.facing == Facing::Right);
+                    if model_left {
+                        self.data.animations.sidestep_left
+                    } else {
+                        self.data.animations.sidestep_right
+                    }
+                }
+            },
             FighterState::HitStun => self.data.animations.hit_reaction,
             FighterState::Airborne => self.data.animations.hit_reaction,
             FighterState::Knockdown => self.data.animations.knockdown,
+            FighterState::Swept => self.data.animations.sweep_fall,
             FighterState::GettingUp => self.data.animations.getting_up,
+            FighterState::Falling => self.data.animations.hit_reaction,
+            FighterState::TechRoll => self.data.animations.getting_up,
+            FighterState::StanceChange => match self.state_machine.stance_target {
+                Some(i) => self.data.stances[i].enter_anim,
+                None => self
+                    .stance()
+                    .map_or(self.data.animations.idle, |s| s.exit_anim),
+            },
         }
     }
 
-    /// Reset fighter state for a new round (keep round_wins).
+    /// Reset fighter state for a new round (keep round_wins and meter).
     pub fn reset_round(&mut self, position: Vec3, facing: Facing) {
+        let edge = self.physics.edge;
         self.state_machine = StateMachine::new();
         self.physics = PhysicsBody::new(position);
-        self.input_buffer = InputBuffer::new();
+        self.physics.edge = edge;
+        self.weapon = WeaponData::get(self.data.default_weapon);
+        self.disarmed = false;
+        self.input_buffer = InputBuffer::with_leniency(self.data.style.modifiers().combo_leniency);
+        self.advancing = false;
+        self.modifiers.clear();
+        self.status.clear();
+        self.armor_hits_absorbed = 0;
+        self.behavior.reset_round();
         self.health = self.data.max_health;
         self.stamina = self.data.max_stamina;
+        self.exhausted_timer = 0;
         self.facing = facing;
-        self.combo_hits_taken = 0;
-        self.last_attack_used = None;
-        self.attack_repeat_count = 0;
+        self.combo.clear();
+        self.juggle_points_taken = 0;
+        self.hitstop = 0;
+        self.stale.clear();
+        self.attack_stale = 1.0;
     }
 }
 
@@ -172,44 +581,72 @@ pub struct GameState {
     pub fighters: [Fighter; 2],
     pub phase: GamePhase,
     pub frame: u32,
-    pub round_timer: u32,
+    /// Frames left in the round, or `None` when rounds have no time limit.
+    pub round_timer: Option<u32>,
     pub current_round: u32,
     pub countdown_timer: u32,
     pub round_over_timer: u32,
     pub last_hit_info: Option<String>,
+    /// Rounds, timers, starting resources, handicaps, stage and combat rules.
+    pub config: MatchConfig,
+    /// Result of the most recently finished round, cleared when the next one starts.
+    pub round_result: Option<RoundResult>,
+    /// Events produced by the latest tick.
+    pub events: Vec<GameEvent>,
+    /// Weapons knocked loose and lying on the stage.
+    pub items: Vec<WeaponItem>,
 }
 
 impl GameState {
-    pub fn new(p1_fighter: FighterId, p2_fighter: FighterId) -> Self {
-        Self {
+    /// Start a match, or fail if `config` doesn't pass `MatchConfig::validate`.
+    pub fn new(
+        p1_fighter: FighterId,
+        p2_fighter: FighterId,
+        config: MatchConfig,
+    ) -> Result<Self, ConfigError> {
+        config.validate()?;
+        let mut game = Self {
             fighters: [
                 Fighter::new(p1_fighter, Vec3::new(-1.5, 0.0, 0.0), Facing::Right),
                 Fighter::new(p2_fighter, Vec3::new(1.5, 0.0, 0.0), Facing::Left),
             ],
             phase: GamePhase::Countdown,
             frame: 0,
-            round_timer: ROUND_TIME_FRAMES,
+            round_timer: config.round_frames(),
             current_round: 1,
-            countdown_timer: 180, // 3 seconds at 60fps
+            countdown_timer: config.countdown_frames,
             round_over_timer: 0,
             last_hit_info: None,
-        }
+            config,
+            round_result: None,
+            events: Vec::new(),
+            items: Vec::new(),
+        };
+        game.set_stage_edge(config.stage_edge);
+        game.apply_starting_resources();
+        Ok(game)
     }
 
-    pub fn new_in_select() -> Self {
-        Self {
+    /// Open on fighter select, or fail if `config` doesn't pass `MatchConfig::validate`.
+    pub fn new_in_select(config: MatchConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+        Ok(Self {
             fighters: [
                 Fighter::new(FighterId::Kael, Vec3::new(-1.5, 0.0, 0.0), Facing::Right),
                 Fighter::new(FighterId::Kael, Vec3::new(1.5, 0.0, 0.0), Facing::Left),
             ],
             phase: GamePhase::FighterSelect,
             frame: 0,
-            round_timer: ROUND_TIME_FRAMES,
+            round_timer: config.round_frames(),
             current_round: 1,
-            countdown_timer: 180,
+            countdown_timer: config.countdown_frames,
             round_over_timer: 0,
             last_hit_info: None,
-        }
+            config,
+            round_result: None,
+            events: Vec::new(),
+            items: Vec::new(),
+        })
     }
 
     /// Set fighters after selection and start countdown.
@@ -218,35 +655,54 @@ impl GameState {
             Fighter::new(p1, Vec3::new(-1.5, 0.0, 0.0), Facing::Right),
             Fighter::new(p2, Vec3::new(1.5, 0.0, 0.0), Facing::Left),
         ];
+        self.set_stage_edge(self.config.stage_edge);
+        self.apply_starting_resources();
         self.phase = GamePhase::Countdown;
-        self.countdown_timer = 180;
+        self.countdown_timer = self.config.countdown_frames;
+    }
+
+    /// Scale each fighter's health and stamina down to the configured starting amounts.
+    fn apply_starting_resources(&mut self) {
+        for (fighter, handicap) in self.fighters.iter_mut().zip(self.config.handicaps) {
+            fighter.health = fighter.data.max_health * self.config.start_health * handicap.health;
+            fighter.stamina = fighter.data.max_stamina * self.config.start_stamina;
+        }
+    }
+
+    /// Choose between a walled stage and an open one where ring-outs end the round.
+    pub fn set_stage_edge(&mut self, edge: StageEdge) {
+        self.config.stage_edge = edge;
+        for fighter in &mut self.fighters {
+            fighter.physics.edge = edge;
+        }
     }
 
     pub fn winner(&self) -> Option<usize> {
-        if self.fighters[0].round_wins >= ROUNDS_TO_WIN {
+        let rounds_to_win = self.config.rounds_to_win();
+        if self.fighters[0].round_wins >= rounds_to_win {
             Some(0)
-        } else if self.fighters[1].round_wins >= ROUNDS_TO_WIN {
+        } else if self.fighters[1].round_wins >= rounds_to_win {
             Some(1)
         } else {
             None
         }
     }
 
+    /// "3", "2", "1" over equal thirds of the configured countdown, then "FIGHT!".
     pub fn countdown_display(&self) -> &'static str {
-        if self.countdown_timer > 120 {
-            "3"
-        } else if self.countdown_timer > 60 {
-            "2"
-        } else if self.countdown_timer > 0 {
-            "1"
-        } else {
-            "FIGHT!"
+        let step = self.config.countdown_frames.div_ceil(3).max(1);
+        match self.countdown_timer.div_ceil(step) {
+            0 => "FIGHT!",
+            1 => "1",
+            2 => "2",
+            _ => "3",
         }
     }
 
     /// Main game tick. Call once per frame (60fps).
     pub fn tick(&mut self, p1_input: &InputState, p2_input: &InputState) {
         self.frame += 1;
+        self.events.clear();
 
         match self.phase {
             GamePhase::FighterSelect => {
@@ -266,6 +722,13 @@ impl GameState {
             }
 
             GamePhase::RoundOver => {
+                // Let ringed-out fighters keep falling during the pause
+                for fighter in &mut self.fighters {
+                    if fighter.state_machine.state == FighterState::Falling {
+                        fighter.physics.tick(DT);
+                    }
+                }
+
                 if self.round_over_timer > 0 {
                     self.round_over_timer -= 1;
                 } else {
@@ -275,18 +738,20 @@ impl GameState {
                     } else {
                         // Start next round
                         self.current_round += 1;
-                        self.fighters[0].reset_round(
-                            Vec3::new(-1.5, 0.0, 0.0),
-                            Facing::Right,
-                        );
-                        self.fighters[1].reset_round(
-                            Vec3::new(1.5, 0.0, 0.0),
-                            Facing::Left,
-                        );
-                        self.round_timer = ROUND_TIME_FRAMES;
-                        self.countdown_timer = 180;
+                        self.fighters[0].reset_round(Vec3::new(-1.5, 0.0, 0.0), Facing::Right);
+                        self.fighters[1].reset_round(Vec3::new(1.5, 0.0, 0.0), Facing::Left);
+                        if self.config.meter_carryover == MeterCarryover::Reset {
+                            for fighter in &mut self.fighters {
+                                fighter.meter = 0.0;
+                            }
+                        }
+                        self.apply_starting_resources();
+                        self.round_timer = self.config.round_frames();
+                        self.countdown_timer = self.config.countdown_frames;
                         self.phase = GamePhase::Countdown;
                         self.last_hit_info = None;
+                        self.round_result = None;
+                        self.items.clear();
                     }
                 }
             }
@@ -299,65 +764,116 @@ impl GameState {
 
     fn tick_fighting(&mut self, p1_input: &InputState, p2_input: &InputState) {
         let inputs = [p1_input.clone(), p2_input.clone()];
+        let frozen = [self.fighters[0].hitstop > 0, self.fighters[1].hitstop > 0];
 
-        // Update input buffers
-        for i in 0..2 {
-            self.fighters[i].input_buffer.set_frame(self.frame);
-            self.fighters[i].input_buffer.expire_old();
+        // Update input buffers. Buffered inputs don't age while frozen in hitstop.
+        for (fighter, was_frozen) in self.fighters.iter_mut().zip(frozen) {
+            if !was_frozen {
+                fighter.input_buffer.tick();
+                fighter.input_buffer.expire_old();
+            }
         }
 
         // Process inputs for each fighter
-        for i in 0..2 {
-            self.process_input(i, &inputs[i]);
+        for (i, input) in inputs.iter().enumerate() {
+            self.process_input(i, input);
         }
 
-        // Apply forward lunge when an attack just started
-        for i in 0..2 {
-            if self.fighters[i].state_machine.state == FighterState::Attacking
-                && self.fighters[i].state_machine.frame_counter == 0
-            {
-                let lunge = self.fighters[i].facing.sign() * ATTACK_LUNGE;
-                self.fighters[i].physics.knockback.x = lunge;
+        // Step the attacker along the attack's movement curve for the coming frame
+        for fighter in &mut self.fighters {
+            if fighter.hitstop > 0 {
+                continue;
+            }
+            if let Some((attack, frame)) = fighter.current_attack_frame() {
+                let speed = attack.movement_at(frame + 1) * fighter.facing.sign();
+                fighter.physics.velocity.x = speed;
             }
         }
 
-        // Update state machines
+        // Update state machines (frozen fighters hold their current frame)
         for fighter in &mut self.fighters {
+            if fighter.hitstop > 0 {
+                continue;
+            }
+            fighter.modifiers.tick();
+            fighter.behavior.tick(fighter.state_machine.state);
+            // Burn wears health down but never lands the finishing blow
+            let burn = fighter.status.tick();
+            if burn > 0.0 {
+                fighter.health = (fighter.health - burn).max(fighter.health.min(1.0));
+            }
+            // Being knocked down shakes off stagger and stun
+            if fighter.state_machine.is_down() {
+                fighter.status.cleanse(StatusKind::Stagger);
+                fighter.status.cleanse(StatusKind::Stun);
+            }
+            let was_rolling = fighter.state_machine.state == FighterState::TechRoll;
             fighter.state_machine.tick();
-            // Reset combo counter when fighter recovers to a neutral state
-            if fighter.state_machine.can_act() {
-                fighter.combo_hits_taken = 0;
+            if was_rolling && fighter.state_machine.state != FighterState::TechRoll {
+                fighter.physics.stop_movement();
+            }
+            // Reset juggle counters when fighter recovers to a neutral state
+            if fighter.can_act() {
+                fighter.juggle_points_taken = 0;
             }
         }
 
-        // Regenerate stamina
+        // A combo is over once its defender can act again
+        for i in 0..2 {
+            if self.fighters[i].can_act() {
+                self.end_combo(i);
+            }
+        }
+
+        // Tech rolls carry the fighter a fixed distance over the roll
         for fighter in &mut self.fighters {
-            if fighter.state_machine.can_act() {
-                fighter.stamina = (fighter.stamina + STAMINA_REGEN_RATE)
-                    .min(fighter.data.max_stamina);
+            if fighter.state_machine.state == FighterState::TechRoll {
+                let dir = match fighter.state_machine.wakeup {
+                    Some(WakeUp::TechRollBack) => -1.0,
+                    _ => 1.0,
+                };
+                let speed = fighter.data.tech_roll_distance / (TECH_ROLL_FRAMES as f32 * DT);
+                fighter.physics.set_movement(Vec3::new(
+                    fighter.facing.sign() * dir * speed,
+                    0.0,
+                    0.0,
+                ));
+            }
+        }
+
+        // Regenerate stamina (not while exhausted)
+        for fighter in &mut self.fighters {
+            if fighter.can_act() && !fighter.is_exhausted() {
+                fighter.stamina =
+                    (fighter.stamina + STAMINA_REGEN_RATE).min(fighter.data.max_stamina);
             }
         }
 
         // Align fighters on Z-axis — keep them on the same plane so attacks
-        // always connect. Both fighters lerp toward their midpoint Z each frame.
-        let mid_z = (self.fighters[0].physics.position.z
-            + self.fighters[1].physics.position.z)
-            * 0.5;
+        // always connect. Both fighters lerp toward their midpoint Z each frame,
+        // except while a sidestep is carrying someone off the line. In free
+        // depth mode they stay wherever they moved to.
+        let hold_depth = self.config.depth_mode == DepthMode::Free
+            || self.fighters.iter().any(|f| f.is_sidestepping());
+        let mid_z =
+            (self.fighters[0].physics.position.z + self.fighters[1].physics.position.z) * 0.5;
         for fighter in &mut self.fighters {
-            fighter.physics.position.z += (mid_z - fighter.physics.position.z) * 0.3;
+            if fighter.hitstop == 0 && !hold_depth {
+                fighter.physics.position.z += (mid_z - fighter.physics.position.z) * 0.3;
+            }
         }
 
         // Update facing (face opponent)
         let p0x = self.fighters[0].physics.position.x;
         let p1x = self.fighters[1].physics.position.x;
-        if self.fighters[0].state_machine.can_act() {
+        if self.fighters[0].can_act() {
             self.fighters[0].facing = if p0x < p1x {
                 Facing::Right
             } else {
                 Facing::Left
             };
         }
-        if self.fighters[1].state_machine.can_act() {
+        if self.fighters[1].can_act() {
             self.fighters[1].facing = if p1x < p0x {
                 Facing::Right
             } else {
@@ -368,8 +884,11 @@ impl GameState {
         // Check combat hits
         self.check_combat();
 
-        // Update physics
+        // Update physics (hitstop holds fighters in place, knockback resumes afterwards)
         for fighter in &mut self.fighters {
+            if fighter.hitstop > 0 {
+                continue;
+            }
             let landed = fighter.physics.tick(DT);
             if landed && fighter.state_machine.state == FighterState::Airborne {
                 fighter.state_machine.land();
@@ -377,6 +896,21 @@ impl GameState {
             }
         }
 
+        // Dropped weapons fly, fall and settle on the floor
+        for item in &mut self.items {
+            item.physics.tick(DT);
+        }
+
+        // Ring-out: a fighter carried over an open edge starts falling
+        for (i, fighter) in self.fighters.iter_mut().enumerate() {
+            if fighter.physics.is_past_edge()
+                && fighter.state_machine.state != FighterState::Falling
+            {
+                fighter.state_machine.enter_falling();
+                self.events.push(GameEvent::RingOut { player: i });
+            }
+        }
+
         // Push fighters apart if they overlap (prevent body clipping)
         {
             let p0x = self.fighters[0].physics.position.x;
@@ -392,12 +926,25 @@ impl GameState {
                     self.fighters[0].physics.position.x += half;
                     self.fighters[1].physics.position.x -= half;
                 }
+                // Pushing apart must never shove a fighter off an open edge
+                for fighter in &mut self.fighters {
+                    fighter.physics.clamp_to_arena();
+                }
+            }
+        }
+
+        self.update_exhaustion();
+
+        // Count down hitstop for fighters that spent this frame frozen
+        for (fighter, was_frozen) in self.fighters.iter_mut().zip(frozen) {
+            if was_frozen {
+                fighter.hitstop = fighter.hitstop.saturating_sub(1);
             }
         }
 
         // Timer
-        if self.round_timer > 0 {
-            self.round_timer -= 1;
+        if let Some(timer) = &mut self.round_timer {
+            *timer = timer.saturating_sub(1);
         }
 
         // Check round end conditions
@@ -407,68 +954,195 @@ impl GameState {
     fn process_input(&mut self, idx: usize, input: &InputState) {
         let fighter = &mut self.fighters[idx];
 
+        // Frozen in hitstop: keep buffering attacks so combos come out afterwards
+        if fighter.hitstop > 0 {
+            fighter.buffer_attack_inputs(input);
+            return;
+        }
+
+        // Stunned fighters can't do anything until it wears off
+        if fighter.status.has(StatusKind::Stun) {
+            if fighter.state_machine.can_act() {
+                fighter.physics.stop_movement();
+                fighter.state_machine.set_idle();
+            }
+            return;
+        }
+        let staggered = fighter.status.has(StatusKind::Stagger);
+
         if !fighter.state_machine.can_act() {
             // Can only release block
-            if fighter.state_machine.state == FighterState::Blocking && !input.block {
+            if fighter.state_machine.state == FighterState::Blocking && (!input.block || staggered)
+            {
                 fighter.state_machine.stop_block();
             }
+            // Technical fighters can cancel a connected normal into their special
+            let special_cost = fighter.get_attack_data(ActiveAttack::Special).stamina_cost;
+            if input.special
+                && fighter.data.style.modifiers().special_cancel
+                && fighter.stamina >= special_cost
+                && fighter.behavior.can_start_attack(ActiveAttack::Special)
+                && matches!(
+                    fighter.state_machine.attack,
+                    Some(
+                        ActiveAttack::Light
+                            | ActiveAttack::Heavy
+                            | ActiveAttack::MidKick
+                            | ActiveAttack::LowKick
+                            | ActiveAttack::Command(_)
+                    )
+                )
+            {
+                let attack_data = fighter.get_attack_data(ActiveAttack::Special);
+                let startup = fighter.startup_frames(ActiveAttack::Special);
+                let active = attack_data.active_frames;
+                let recovery = attack_data.recovery_frames;
+                if fighter.state_machine.cancel_into_attack(
+                    ActiveAttack::Special,
+                    startup,
+                    active,
+                    recovery,
+                ) {
+                    fighter.track_attack_used(ActiveAttack::Special);
+                    fighter.stamina -= special_cost;
+                }
+            }
+            // Wake-up choice while knocked down
+            if fighter.state_machine.is_down() && fighter.is_alive() {
+                if let Some(choice) = wakeup_choice(input) {
+                    let rolled = fighter.state_machine.choose_wakeup(choice)
+                        && matches!(choice, WakeUp::TechRollForward | WakeUp::TechRollBack);
+                    // Rolling on the ground puts out a burn
+                    if rolled {
+                        fighter.status.cleanse(StatusKind::Burn);
+                    }
+                }
+            }
+            return;
+        }
+
+        fighter.advancing = (input.move_forward && !input.move_back) || input.dash;
+
+        // The fighter's own mechanic gets first say over the input
+        if fighter.behavior.handle_input(input) == InputOverride::Hold {
+            fighter.physics.stop_movement();
+            fighter.state_machine.set_idle();
             return;
         }
 
-        // Block
-        if input.block {
+        // Block (a staggered guard can't be raised)
+        if input.block && !staggered {
             fighter.state_machine.start_block();
             fighter.physics.stop_movement();
             return;
         }
 
-        // Dash
-        if input.dash && fighter.stamina >= DASH_STAMINA_COST {
-            if fighter.state_machine.start_dash(fighter.data.dash_frames) {
-                fighter.stamina -= DASH_STAMINA_COST;
-                let dash_vel = Vec3::new(
-                    fighter.facing.sign() * fighter.data.dash_speed,
-                    0.0,
-                    0.0,
-                );
-                fighter.physics.set_movement(dash_vel);
+        // Pick up a nearby weapon while unarmed
+        if input.pickup && fighter.weapon.weapon_type == WeaponType::Unarmed {
+            let pos = fighter.physics.position;
+            let nearby = self.items.iter().position(|item| {
+                item.physics.grounded
+                    && (item.physics.position.x - pos.x).abs() <= PICKUP_RANGE
+                    && (item.physics.position.z - pos.z).abs() <= PICKUP_RANGE
+            });
+            if let Some(i) = nearby {
+                let item = self.items.remove(i);
+                fighter.pick_up(item.weapon_type);
+                fighter.physics.stop_movement();
+                self.events.push(GameEvent::WeaponPickedUp {
+                    player: idx,
+                    weapon: item.weapon_type,
+                });
                 return;
             }
         }
 
-        // Check for combos before processing individual attacks
-        if input.light_attack {
-            fighter.input_buffer.push(crate::input::InputAction::LightAttack);
-        }
-        if input.heavy_attack {
-            fighter.input_buffer.push(crate::input::InputAction::HeavyAttack);
+        // Stance button cycles through the fighter's stances, then back to base
+        if input.stance && !fighter.data.stances.is_empty() {
+            let stances = fighter.data.stances;
+            let (target, frames) = match fighter.state_machine.stance {
+                None => (Some(0), stances[0].enter_frames),
+                Some(i) if i + 1 < stances.len() => (Some(i + 1), stances[i + 1].enter_frames),
+                Some(i) => (None, stances[i].exit_frames),
+            };
+            if fighter.state_machine.change_stance(target, frames) {
+                fighter.physics.stop_movement();
+                return;
+            }
         }
-        if input.special {
-            fighter.input_buffer.push(crate::input::InputAction::Special);
+
+        // Dash: back + dash backdashes, left/right + dash sidesteps
+        if input.dash {
+            let data = fighter.data;
+            let sign = fighter.facing.sign();
+            let (kind, velocity, frames, cost) = if input.move_back {
+                let b = &data.backdash;
+                (
+                    DashKind::Back,
+                    Vec3::new(-sign * b.speed, 0.0, 0.0),
+                    b.frames,
+                    b.stamina_cost,
+                )
+            } else if input.move_left != input.move_right {
+                let side = &data.sidestep;
+                let z = if input.move_left {
+                    -side.speed
+                } else {
+                    side.speed
+                };
+                (
+                    DashKind::Side,
+                    Vec3::new(0.0, 0.0, z),
+                    side.frames,
+                    side.stamina_cost,
+                )
+            } else {
+                let f = &data.dash;
+                (
+                    DashKind::Forward,
+                    Vec3::new(sign * f.speed, 0.0, 0.0),
+                    f.frames,
+                    f.stamina_cost,
+                )
+            };
+            if fighter.stamina >= cost && fighter.state_machine.start_dash(kind, frames) {
+                fighter.stamina -= cost;
+                fighter.physics.set_movement(velocity);
+                return;
+            }
         }
 
+        // Check for combos before processing individual attacks
+        fighter.buffer_attack_inputs(input);
+
         if let Some(combo) = fighter.input_buffer.detect_combo() {
             let attack_type = match combo {
                 ComboType::ThreeHit => ActiveAttack::ComboFinisher,
                 ComboType::Super => ActiveAttack::Super,
             };
             let attack_data = fighter.get_attack_data(attack_type);
+            let stamina_cost = attack_data.stamina_cost;
             let can_afford = match combo {
-                ComboType::Super => fighter.stamina >= SUPER_STAMINA_COST,
+                ComboType::Super => fighter.meter >= SUPER_METER_COST,
                 _ => true,
-            };
+            } && fighter.stamina >= stamina_cost
+                && fighter.behavior.can_start_attack(attack_type);
 
             if can_afford {
-                let startup = (attack_data.startup_frames as f32 / fighter.weapon.attack_speed) as u32;
+                let startup = fighter.startup_frames(attack_type);
                 let active = attack_data.active_frames;
                 let recovery = attack_data.recovery_frames;
 
-                if fighter.state_machine.start_attack(attack_type, startup, active, recovery) {
+                if fighter
+                    .state_machine
+                    .start_attack(attack_type, startup, active, recovery)
+                {
                     fighter.track_attack_used(attack_type);
                     fighter.input_buffer.clear();
+                    fighter.stamina -= stamina_cost;
                     fighter.physics.stop_movement();
                     if matches!(combo, ComboType::Super) {
-                        fighter.stamina -= SUPER_STAMINA_COST;
+                        fighter.meter -= SUPER_METER_COST;
                     }
                     return;
                 }
@@ -476,15 +1150,27 @@ impl GameState {
         }
 
         // Individual attacks — helper to start attack + track stale moves
-        let try_attack = |fighter: &mut Fighter, attack: ActiveAttack, stamina_cost: f32| -> bool {
+        let try_attack = |fighter: &mut Fighter, attack: ActiveAttack| -> bool {
+            let attack_data = fighter.get_attack_data(attack);
+            // The enhanced special is paid for with meter instead
+            let stamina_cost = if attack == ActiveAttack::EnhancedSpecial {
+                0.0
+            } else {
+                attack_data.stamina_cost
+            };
             if stamina_cost > 0.0 && fighter.stamina < stamina_cost {
                 return false;
             }
-            let attack_data = fighter.get_attack_data(attack);
-            let startup = (attack_data.startup_frames as f32 / fighter.weapon.attack_speed) as u32;
+            if !fighter.behavior.can_start_attack(attack) {
+                return false;
+            }
+            let startup = fighter.startup_frames(attack);
             let active = attack_data.active_frames;
             let recovery = attack_data.recovery_frames;
-            if fighter.state_machine.start_attack(attack, startup, active, recovery) {
+            if fighter
+                .state_machine
+                .start_attack(attack, startup, active, recovery)
+            {
                 fighter.track_attack_used(attack);
                 if stamina_cost > 0.0 {
                     fighter.stamina -= stamina_cost;
@@ -496,29 +1182,47 @@ impl GameState {
             }
         };
 
-        if input.light_attack && try_attack(fighter, ActiveAttack::Light, 0.0) {
+        // Enhanced special: Heavy + Special together, paid for with meter
+        if input.heavy_attack
+            && input.special
+            && fighter.meter >= ENHANCED_SPECIAL_METER_COST
+            && try_attack(fighter, ActiveAttack::EnhancedSpecial)
+        {
+            fighter.meter -= ENHANCED_SPECIAL_METER_COST;
+            return;
+        }
+
+        // Normals, turned into command normals by a held direction
+        let light = fighter.resolve_normal(ActiveAttack::Light, input);
+        if input.light_attack && try_attack(fighter, light) {
             return;
         }
-        if input.heavy_attack && try_attack(fighter, ActiveAttack::Heavy, 0.0) {
+        let heavy = fighter.resolve_normal(ActiveAttack::Heavy, input);
+        if input.heavy_attack && try_attack(fighter, heavy) {
             return;
         }
-        if input.special && try_attack(fighter, ActiveAttack::Special, SPECIAL_STAMINA_COST) {
+        if input.special && try_attack(fighter, ActiveAttack::Special) {
             return;
         }
-        if input.mid_kick && try_attack(fighter, ActiveAttack::MidKick, 0.0) {
+        let mid_kick = fighter.resolve_normal(ActiveAttack::MidKick, input);
+        if input.mid_kick && try_attack(fighter, mid_kick) {
             return;
         }
-        if input.low_kick && try_attack(fighter, ActiveAttack::LowKick, 0.0) {
+        let low_kick = fighter.resolve_normal(ActiveAttack::LowKick, input);
+        if input.low_kick && try_attack(fighter, low_kick) {
             return;
         }
-        if input.aerial && try_attack(fighter, ActiveAttack::Aerial, AERIAL_STAMINA_COST) {
+        if input.aerial && try_attack(fighter, ActiveAttack::Aerial) {
             return;
         }
 
         // Movement
         if input.has_movement() {
             let mut vel = Vec3::ZERO;
-            let speed = fighter.data.move_speed;
+            let mut speed = fighter.data.move_speed * fighter.effective_stats().speed;
+            if fighter.is_exhausted() {
+                speed *= EXHAUSTED_MOVE_SPEED;
+            }
             if input.move_forward {
                 vel.x += fighter.facing.sign() * speed;
             }
@@ -540,164 +1244,503 @@ impl GameState {
     }
 
     fn check_combat(&mut self) {
-        // Check each fighter's attack against the other
-        for attacker_idx in 0..2 {
-            let defender_idx = 1 - attacker_idx;
+        if self.check_clash() {
+            return;
+        }
 
-            if !self.fighters[attacker_idx].state_machine.is_attack_active() {
-                continue;
-            }
-            if self.fighters[attacker_idx].state_machine.hit_connected {
-                continue;
-            }
-            if !self.fighters[defender_idx].state_machine.is_vulnerable() {
-                continue;
-            }
+        // Phase 1: find every hit from the state both fighters are in right now, so
+        // resolving P1's hit can't interrupt P2's attack before it is checked
+        let pending = [self.detect_hit(0), self.detect_hit(1)];
+        if pending.iter().all(Option::is_some) {
+            self.events.push(GameEvent::Trade);
+        }
 
-            let attack_type = match self.fighters[attacker_idx].state_machine.attack {
-                Some(a) => a,
-                None => continue,
+        // Phase 2: apply them
+        for hit in pending.into_iter().flatten() {
+            self.apply_hit(hit);
+        }
+    }
+
+    /// Attacks whose hitboxes meet cancel each other out and push both fighters back.
+    fn check_clash(&mut self) -> bool {
+        let mut hitboxes = Vec::with_capacity(2);
+        for fighter in &self.fighters {
+            let sm = &fighter.state_machine;
+            let Some(attack) = sm.attack.filter(|_| sm.is_attack_active()) else {
+                return false;
             };
+            let data = fighter.get_attack_data(attack);
+            if data.class == AttackClass::Throw || !sm.can_hit(data.hits, data.rehit_interval) {
+                return false;
+            }
+            hitboxes.push(combat::attack_hitbox(
+                fighter.physics.position,
+                fighter.facing,
+                data,
+            ));
+        }
+        if !hitboxes[0].overlaps(&hitboxes[1]) {
+            return false;
+        }
 
-            // Copy all data we need before mutating
-            let attack_data = self.fighters[attacker_idx].get_attack_data(attack_type).clone();
-            let attacker_pos = self.fighters[attacker_idx].physics.position;
-            let attacker_facing = self.fighters[attacker_idx].facing;
-            let attacker_weapon = self.fighters[attacker_idx].weapon;
-            let attacker_defense = self.fighters[attacker_idx].data.defense;
-            let stale_multiplier = self.fighters[attacker_idx].stale_move_multiplier();
-            let defender_pos = self.fighters[defender_idx].physics.position;
-            let defender_hurtbox = self.fighters[defender_idx].data.hurtbox;
-            let defender_defense = self.fighters[defender_idx].data.defense;
-            let combo_hits = self.fighters[defender_idx].combo_hits_taken;
-            let is_blocking =
-                self.fighters[defender_idx].state_machine.state == FighterState::Blocking;
-
-            let hit_result = combat::check_hit(
-                attacker_pos,
-                attacker_facing,
-                &attack_data,
-                attacker_weapon,
-                attacker_defense,
-                defender_pos,
-                &defender_hurtbox,
-                defender_defense,
-                is_blocking,
-                combo_hits,
-                stale_multiplier,
-            );
+        let p1_x = self.fighters[0].physics.position.x;
+        let p2_x = self.fighters[1].physics.position.x;
+        let dir = if p1_x <= p2_x { -1.0 } else { 1.0 };
+        for (fighter, sign) in self.fighters.iter_mut().zip([dir, -dir]) {
+            fighter.state_machine.cancel_attack();
+            fighter.physics.stop_movement();
+            fighter
+                .physics
+                .apply_knockback(Vec3::new(sign * CLASH_PUSHBACK, 0.0, 0.0));
+            fighter.hitstop = CLASH_HITSTOP_FRAMES;
+        }
+        self.events.push(GameEvent::Clash);
+        self.last_hit_info = Some("Clash!".to_string());
+        true
+    }
 
-            if let Some(hit) = hit_result {
-                // Apply damage
-                self.fighters[defender_idx].health =
-                    (self.fighters[defender_idx].health - hit.damage).max(0.0);
+    /// Check whether `attacker_idx`'s attack connects, without changing any state.
+    fn detect_hit(&self, attacker_idx: usize) -> Option<PendingHit> {
+        let defender_idx = 1 - attacker_idx;
+        let attacker = &self.fighters[attacker_idx];
+        let defender = &self.fighters[defender_idx];
+
+        if !attacker.state_machine.is_attack_active() {
+            return None;
+        }
+        let attack_type = attacker.state_machine.attack?;
+        let attack_data = attacker.get_attack_data(attack_type).clone();
+        if !attacker
+            .state_machine
+            .can_hit(attack_data.hits, attack_data.rehit_interval)
+        {
+            return None;
+        }
+        let tracked = attacker
+            .side_of(defender.physics.position)
+            .is_some_and(|side| attack_data.tracking.covers(side));
+        if !defender.can_be_hit_by(&attack_data, tracked) {
+            return None;
+        }
+        // A tracking attack swings its hitbox around toward the defender
+        let mut aim = attacker.physics.position;
+        if tracked {
+            aim.z += (defender.physics.position.z - aim.z).clamp(-TRACKING_REACH, TRACKING_REACH);
+        }
 
-                // Apply knockback
-                self.fighters[defender_idx]
-                    .physics
-                    .apply_knockback(hit.knockback);
+        let mut stale_multiplier = attacker.attack_stale;
+        if attack_type == ActiveAttack::EnhancedSpecial {
+            stale_multiplier *= ENHANCED_SPECIAL_DAMAGE;
+        }
+        let defender_state = defender.state_machine.state;
+        let is_blocking = defender_state == FighterState::Blocking;
+        let combo_hits = defender.combo.hits();
+
+        let attacker_ctx = AttackerContext {
+            position: aim,
+            facing: attacker.facing,
+            weapon: attacker.weapon,
+            stats: attacker.effective_stats(),
+            style: attacker.data.style.modifiers(),
+            advancing: attacker.advancing,
+            stale_multiplier,
+        };
+        let defender_ctx = DefenderContext {
+            position: defender.physics.position,
+            hurtbox: defender.hurtbox(),
+            stats: defender.effective_stats(),
+            style: defender.data.style.modifiers(),
+            resistances: &defender.data.resistances,
+            blocking: is_blocking,
+            combo_hits,
+        };
+        let mut hit = combat::check_hit(
+            &attack_data,
+            &attacker_ctx,
+            &defender_ctx,
+            &self.config.rules,
+        )?;
+        hit.scale_damage(
+            self.config.handicaps[attacker_idx].damage
+                * attacker.behavior.damage_dealt_multiplier()
+                * defender.behavior.damage_taken_multiplier(is_blocking),
+        );
+
+        Some(PendingHit {
+            attacker_idx,
+            // Only the last hit of a multi-hit attack launches, knocks down or disarms
+            final_hit: attacker.state_machine.hits_landed + 1 >= attack_data.hits,
+            first_hit: attacker.state_machine.hits_landed == 0,
+            // Armor soaks strikes and projectiles, but throws go straight through it
+            armored: !is_blocking
+                && attack_data.class != AttackClass::Throw
+                && defender.has_armor(),
+            attack_data,
+            hit,
+            attacker_pos: attacker.physics.position,
+            attacker_advancing: attacker.advancing,
+            defender_state,
+            is_blocking,
+            combo_hits,
+        })
+    }
 
-                // Track combo hits on the defender
-                if !is_blocking {
-                    self.fighters[defender_idx].combo_hits_taken += 1;
-                }
+    /// Apply a hit found by `detect_hit`.
+    fn apply_hit(&mut self, pending: PendingHit) {
+        let PendingHit {
+            attacker_idx,
+            attack_data,
+            hit,
+            attacker_pos,
+            attacker_advancing,
+            defender_state,
+            is_blocking,
+            armored,
+            final_hit,
+            first_hit,
+            combo_hits,
+        } = pending;
+        let defender_idx = 1 - attacker_idx;
+        let attacker_style = self.fighters[attacker_idx].data.style.modifiers();
+        let defender_style = self.fighters[defender_idx].data.style.modifiers();
+
+        // Apply damage
+        self.fighters[defender_idx].health =
+            (self.fighters[defender_idx].health - hit.damage).max(0.0);
+        self.fighters[attacker_idx]
+            .behavior
+            .on_hit_landed(hit.damage, is_blocking);
+        self.fighters[defender_idx]
+            .behavior
+            .on_hit_taken(hit.damage, is_blocking);
+
+        // Apply knockback (armored fighters hold their ground, and earlier
+        // hits of a multi-hit attack only nudge so the rest can connect)
+        if !armored {
+            let knockback = if final_hit {
+                hit.knockback
+            } else {
+                Vec3::new(hit.knockback.x * MULTI_HIT_KNOCKBACK, 0.0, 0.0)
+            };
+            self.fighters[defender_idx]
+                .physics
+                .apply_knockback(knockback);
+        }
 
-                // Apply state change based on hit severity
-                let is_ko = self.fighters[defender_idx].health <= 0.0;
-                let combo_break = self.fighters[defender_idx].combo_hits_taken >= COMBO_BREAKER_HITS;
-                let causes_knockdown = matches!(
-                    attack_type,
-                    ActiveAttack::ComboFinisher | ActiveAttack::Super | ActiveAttack::LowKick
-                );
+        // Build meter: attacker for dealing damage, defender for taking or blocking it
+        let mut meter_dealt = hit.damage * METER_GAIN_DEALT;
+        if attacker_advancing {
+            meter_dealt *= attacker_style.advancing_meter;
+        }
+        self.fighters[attacker_idx].gain_meter(meter_dealt);
+        if is_blocking {
+            self.fighters[defender_idx].gain_meter(METER_GAIN_BLOCKED);
+        } else {
+            self.fighters[defender_idx].gain_meter(hit.damage * METER_GAIN_TAKEN);
+        }
 
-                if is_ko {
-                    // KO: enter knockdown and stay down (very long timer)
-                    self.fighters[defender_idx]
-                        .state_machine
-                        .enter_knockdown(9999);
-                } else if combo_break {
-                    // Combo breaker: auto-knockdown, push fighters far apart
-                    self.fighters[defender_idx]
-                        .state_machine
-                        .enter_knockdown(25);
-                    self.fighters[defender_idx].combo_hits_taken = 0;
-                    // Push both fighters apart
-                    let push_dir = if defender_pos.x >= attacker_pos.x { 1.0 } else { -1.0 };
-                    self.fighters[defender_idx]
-                        .physics
-                        .apply_knockback(Vec3::new(push_dir * 8.0, 0.0, 0.0));
-                    self.fighters[attacker_idx]
-                        .physics
-                        .apply_knockback(Vec3::new(-push_dir * 5.0, 0.0, 0.0));
-                } else if hit.launches {
-                    self.fighters[defender_idx].state_machine.enter_airborne();
-                } else if !is_blocking && causes_knockdown {
-                    // Hard knockdown: fall down, then get up
-                    self.fighters[defender_idx]
-                        .state_machine
-                        .enter_knockdown(40);
-                } else if !is_blocking {
-                    self.fighters[defender_idx]
-                        .state_machine
-                        .enter_hitstun(hit.hitstun_frames);
-                }
+        // Holding a block costs stamina for every hit absorbed
+        if is_blocking {
+            let defender = &mut self.fighters[defender_idx];
+            let drain = (BLOCK_STAMINA_COST + hit.damage * BLOCK_STAMINA_PER_DAMAGE)
+                * defender_style.block_stamina;
+            defender.stamina = (defender.stamina - drain).max(0.0);
+        }
 
-                // Mark hit as connected
-                self.fighters[attacker_idx].state_machine.hit_connected = true;
+        // Track combo hits on the defender
+        if !is_blocking && !armored {
+            self.fighters[defender_idx].combo.record_hit(
+                self.frame,
+                attack_data.name,
+                hit.damage,
+                hit.breakdown.combo,
+            );
+        }
 
-                // Record hit info
-                let combo_label = if combo_hits > 0 {
-                    format!(" [{} hit combo]", combo_hits + 1)
-                } else {
-                    String::new()
-                };
-                self.last_hit_info = Some(format!(
-                    "P{} {} -> P{} for {:.1} dmg{}{}",
-                    attacker_idx + 1,
-                    attack_data.name,
-                    defender_idx + 1,
-                    hit.damage,
-                    if hit.was_blocked { " (BLOCKED)" } else { "" },
-                    combo_label,
-                ));
-            }
+        // Landed hits go into the stale queue, once per attack
+        if first_hit && !is_blocking {
+            self.fighters[attacker_idx].stale.record(attack_data.name);
         }
-    }
 
-    fn check_round_end(&mut self) {
-        let p1_dead = !self.fighters[0].is_alive();
-        let p2_dead = !self.fighters[1].is_alive();
-        let time_up = self.round_timer == 0;
-
-        if p1_dead || p2_dead || time_up {
-            // Determine round winner
-            if p1_dead && !p2_dead {
-                self.fighters[1].round_wins += 1;
-            } else if p2_dead && !p1_dead {
-                self.fighters[0].round_wins += 1;
-            } else if time_up {
-                // Higher health percentage wins
-                if self.fighters[0].health_pct() > self.fighters[1].health_pct() {
-                    self.fighters[0].round_wins += 1;
-                } else if self.fighters[1].health_pct() > self.fighters[0].health_pct() {
-                    self.fighters[1].round_wins += 1;
-                }
-                // Draw: no one gets a point
+        // Juggle, OTG and hitstun-loop hits spend the defender's juggle budget
+        let juggled = matches!(
+            defender_state,
+            FighterState::Airborne
+                | FighterState::Knockdown
+                | FighterState::Swept
+                | FighterState::HitStun
+        );
+        if juggled && first_hit {
+            self.fighters[defender_idx].juggle_points_taken += attack_data.juggle_points;
+        }
+        // A grounded hitstun loop that spends the whole budget knocks the defender free
+        let loop_broken = defender_state == FighterState::HitStun
+            && self.fighters[defender_idx].juggle_points_taken >= JUGGLE_BUDGET;
+
+        // Apply state change based on hit severity
+        let is_ko = self.fighters[defender_idx].health <= 0.0;
+        let knockdown = attack_data.knockdown.filter(|_| final_hit && !is_blocking);
+
+        if is_ko {
+            // KO: enter knockdown and stay down (very long timer)
+            self.fighters[defender_idx]
+                .state_machine
+                .enter_knockdown(9999, KnockdownKind::Hard);
+        } else if armored {
+            // Armor: the damage lands but the attack carries on
+            self.fighters[defender_idx].armor_hits_absorbed += 1;
+        } else if loop_broken {
+            self.fighters[defender_idx]
+                .state_machine
+                .enter_knockdown(LOOP_BREAK_KNOCKDOWN_FRAMES, KnockdownKind::Soft);
+            let push = (self.fighters[defender_idx].physics.position.x - attacker_pos.x).signum();
+            self.fighters[defender_idx]
+                .physics
+                .apply_knockback(Vec3::new(push * LOOP_BREAK_PUSH, 0.0, 0.0));
+        } else if hit.launches && final_hit {
+            self.fighters[defender_idx].state_machine.enter_airborne();
+        } else if defender_state == FighterState::Airborne {
+            // Juggle: keep them in the air with a small pop
+            let defender = &mut self.fighters[defender_idx];
+            defender.physics.velocity.y = 0.0;
+            defender
+                .physics
+                .apply_knockback(Vec3::new(hit.knockback.x, JUGGLE_POP_VELOCITY, 0.0));
+            defender.state_machine.enter_airborne();
+        } else if matches!(
+            defender_state,
+            FighterState::Knockdown | FighterState::Swept
+        ) {
+            // OTG: the hit keeps them down a little longer
+            self.fighters[defender_idx]
+                .state_machine
+                .extend_down(OTG_KNOCKDOWN_FRAMES);
+        } else if knockdown == Some(KnockdownKind::Sweep) {
+            // Sweep: legs taken out, a quick fall with its own wake-up
+            self.fighters[defender_idx].state_machine.enter_swept();
+        } else if let Some(kind) = knockdown {
+            // Knockdown: fall down, then get up
+            self.fighters[defender_idx]
+                .state_machine
+                .enter_knockdown(40, kind);
+        } else if !is_blocking {
+            let defender = &mut self.fighters[defender_idx];
+            let mut hitstun = if defender.is_exhausted() {
+                (hit.hitstun_frames as f32 * EXHAUSTED_HITSTUN) as u32
+            } else {
+                hit.hitstun_frames
+            };
+            if !final_hit {
+                hitstun = hitstun.max(attack_data.rehit_interval + MULTI_HIT_STUN_PADDING);
             }
+            defender.state_machine.enter_hitstun(hitstun);
+        }
 
-            self.phase = GamePhase::RoundOver;
-            self.round_over_timer = 120; // 2 seconds pause
+        // Guard break: a blocked hit that empties stamina leaves the defender open
+        let guard_broken = is_blocking && self.fighters[defender_idx].stamina <= 0.0;
+        if guard_broken {
+            self.fighters[defender_idx]
+                .state_machine
+                .enter_hitstun(GUARD_BREAK_STUN_FRAMES);
+            self.events.push(GameEvent::GuardBreak {
+                player: defender_idx,
+            });
         }
-    }
 
-    pub fn round_time_remaining(&self) -> f32 {
-        self.round_timer as f32 / 60.0
-    }
-}
+        // Landed hits can buff the attacker and debuff the defender
+        if !is_blocking {
+            if let Some(buff) = attack_data.on_hit_buff {
+                self.fighters[attacker_idx].modifiers.apply(buff);
+            }
+            if let Some(debuff) = attack_data.on_hit_debuff {
+                self.fighters[defender_idx].modifiers.apply(debuff);
+            }
+        }
+
+        // Status effects; only stagger gets through a block
+        if let Some(effect) = attack_data.on_hit_status {
+            if !is_blocking || effect.applies_through_block() {
+                let defender = &mut self.fighters[defender_idx];
+                defender.status.apply(effect);
+                if effect.kind == StatusKind::Stagger
+                    && defender.state_machine.state == FighterState::Blocking
+                {
+                    defender.state_machine.stop_block();
+                }
+            }
+        }
+
+        // Disarming attacks and guard breaks knock the weapon loose
+        if (attack_data.disarms && final_hit && !is_blocking && !armored) || guard_broken {
+            self.disarm(defender_idx, attacker_pos.x);
+        }
+
+        // Mark hit as connected
+        self.fighters[attacker_idx].state_machine.register_hit();
+
+        // Freeze both fighters on impact
+        self.fighters[attacker_idx].hitstop = hit.hitstop_frames;
+        self.fighters[defender_idx].hitstop = hit.hitstop_frames;
+
+        self.events.push(GameEvent::Hit {
+            attacker: attacker_idx,
+            attack: attack_data.name,
+            breakdown: hit.breakdown,
+            blocked: hit.was_blocked,
+        });
+
+        // Record hit info
+        let combo_label = if combo_hits > 0 {
+            format!(" [{} hit combo]", combo_hits + 1)
+        } else {
+            String::new()
+        };
+        self.last_hit_info = Some(format!(
+            "P{} {} -> P{} for {:.1} {} dmg{}{}",
+            attacker_idx + 1,
+            attack_data.name,
+            defender_idx + 1,
+            hit.damage,
+            hit.breakdown.damage_type,
+            if hit.was_blocked { " (BLOCKED)" } else { "" },
+            combo_label,
+        ));
+    }
+
+    /// Knock a fighter's weapon away from `attacker_x` and leave it on the stage.
+    fn disarm(&mut self, idx: usize, attacker_x: f32) {
+        let fighter = &mut self.fighters[idx];
+        let Some(weapon) = fighter.drop_weapon() else {
+            return;
+        };
+        let dir = if fighter.physics.position.x >= attacker_x {
+            1.0
+        } else {
+            -1.0
+        };
+        let position = fighter.physics.position + Vec3::new(0.0, 1.0, 0.0);
+        let impulse = Vec3::new(DISARM_FLING.x * dir, DISARM_FLING.y, 0.0);
+        self.items
+            .push(WeaponItem::dropped(weapon, position, impulse));
+        self.events.push(GameEvent::Disarmed {
+            player: idx,
+            weapon,
+        });
+    }
+
+    /// Running dry on stamina exhausts a fighter until the cooldown runs out.
+    fn update_exhaustion(&mut self) {
+        for (i, fighter) in self.fighters.iter_mut().enumerate() {
+            if fighter.is_exhausted() {
+                fighter.exhausted_timer -= 1;
+                if !fighter.is_exhausted() {
+                    // Recover with some stamina, so a fighter who can't regenerate yet
+                    // (e.g. still in hitstun) isn't exhausted again on the next frame
+                    fighter.stamina = fighter
+                        .stamina
+                        .max(fighter.data.max_stamina * EXHAUSTED_RECOVERY_STAMINA);
+                    self.events.push(GameEvent::Recovered { player: i });
+                }
+            } else if fighter.stamina <= 0.0 {
+                fighter.stamina = 0.0;
+                fighter.exhausted_timer = EXHAUSTED_COOLDOWN_FRAMES;
+                self.events.push(GameEvent::Exhausted { player: i });
+            }
+        }
+    }
+
+    fn check_round_end(&mut self) {
+        let dead = [!self.fighters[0].is_alive(), !self.fighters[1].is_alive()];
+        let out = [
+            self.fighters[0].state_machine.state == FighterState::Falling,
+            self.fighters[1].state_machine.state == FighterState::Falling,
+        ];
+        let time_up = self.round_timer == Some(0);
+
+        // The fighter who did NOT lose wins; both losing is a draw
+        let survivor = |lost: [bool; 2]| match lost {
+            [true, false] => Some(1),
+            [false, true] => Some(0),
+            _ => None,
+        };
+
+        let result = if dead[0] || dead[1] {
+            RoundResult {
+                winner: survivor(dead),
+                reason: RoundEndReason::Knockout,
+            }
+        } else if out[0] || out[1] {
+            RoundResult {
+                winner: survivor(out),
+                reason: RoundEndReason::RingOut,
+            }
+        } else if time_up {
+            // Higher health percentage wins; equal health is a draw
+            let winner = if self.fighters[0].health_pct() > self.fighters[1].health_pct() {
+                Some(0)
+            } else if self.fighters[1].health_pct() > self.fighters[0].health_pct() {
+                Some(1)
+            } else {
+                None
+            };
+            RoundResult {
+                winner,
+                reason: RoundEndReason::TimeUp,
+            }
+        } else {
+            return;
+        };
+
+        if let Some(w) = result.winner {
+            self.fighters[w].round_wins += 1;
+        }
+        // A combo that finished the round still gets reported
+        self.end_combo(0);
+        self.end_combo(1);
+        self.round_result = Some(result);
+        self.events.push(GameEvent::RoundOver(result));
+        self.phase = GamePhase::RoundOver;
+        self.round_over_timer = self.config.round_over_frames;
+    }
+
+    /// End the combo `defender_idx` is taking, reporting it if it was a real combo.
+    fn end_combo(&mut self, defender_idx: usize) {
+        if let Some(combo) = self.fighters[defender_idx].combo.end(self.frame) {
+            self.events.push(GameEvent::ComboEnded {
+                attacker: 1 - defender_idx,
+                combo,
+            });
+        }
+    }
+
+    /// Seconds left in the round, or `None` when there's no time limit.
+    pub fn round_time_remaining(&self) -> Option<f32> {
+        self.round_timer.map(|frames| frames as f32 / 60.0)
+    }
+}
+
+/// Dash + direction tech rolls, dash alone quick-rises, holding block stays down.
+fn wakeup_choice(input: &InputState) -> Option<WakeUp> {
+    if input.dash && input.move_forward {
+        Some(WakeUp::TechRollForward)
+    } else if input.dash && input.move_back {
+        Some(WakeUp::TechRollBack)
+    } else if input.dash {
+        Some(WakeUp::QuickRise)
+    } else if input.block {
+        Some(WakeUp::Delayed)
+    } else {
+        None
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::Handicap;
+    use crate::fighter::FighterStyle;
+    use crate::status::StatusEffect;
+    use crate::weapon::DamageType;
 
     fn empty_input() -> InputState {
         InputState::default()
@@ -705,13 +1748,13 @@ mod tests {
 
     #[test]
     fn game_starts_in_countdown() {
-        let game = GameState::new(FighterId::Kael, FighterId::Knight);
+        let game = new_game(FighterId::Kael, FighterId::Knight);
         assert_eq!(game.phase, GamePhase::Countdown);
     }
 
     #[test]
     fn countdown_transitions_to_fighting() {
-        let mut game = GameState::new(FighterId::Kael, FighterId::Knight);
+        let mut game = new_game(FighterId::Kael, FighterId::Knight);
         let input = empty_input();
         for _ in 0..200 {
             game.tick(&input, &input);
@@ -719,10 +1762,53 @@ mod tests {
         assert_eq!(game.phase, GamePhase::Fighting);
     }
 
+    #[test]
+    fn new_game_validates_its_config() {
+        let config = MatchConfig {
+            rounds: 4,
+            ..MatchConfig::default()
+        };
+        assert_eq!(
+            GameState::new(FighterId::Kael, FighterId::Knight, config).err(),
+            Some(ConfigError::Rounds(4))
+        );
+        assert!(GameState::new_in_select(config).is_err());
+
+        let open = MatchConfig {
+            stage_edge: StageEdge::Open,
+            ..MatchConfig::default()
+        };
+        let game = GameState::new(FighterId::Kael, FighterId::Knight, open).unwrap();
+        assert!(game
+            .fighters
+            .iter()
+            .all(|f| f.physics.edge == StageEdge::Open));
+    }
+
+    #[test]
+    fn countdown_display_follows_the_configured_length() {
+        let config = MatchConfig {
+            countdown_frames: 90,
+            ..MatchConfig::default()
+        };
+        let mut game = GameState::new(FighterId::Kael, FighterId::Knight, config).unwrap();
+        let mut shown = Vec::new();
+        while game.phase == GamePhase::Countdown {
+            let display = game.countdown_display();
+            if shown.last() != Some(&display) {
+                shown.push(display);
+            }
+            if game.countdown_timer == 60 {
+                assert_eq!(display, "2");
+            }
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(shown, ["3", "2", "1", "FIGHT!"]);
+    }
+
     #[test]
     fn fighter_takes_damage() {
-        let mut game = GameState::new(FighterId::Kael, FighterId::Kael);
-        game.phase = GamePhase::Fighting;
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
 
         // Move fighters close
         game.fighters[0].physics.position = Vec3::new(0.0, 0.0, 0.0);
@@ -745,8 +1831,7 @@ mod tests {
 
     #[test]
     fn blocking_reduces_damage() {
-        let mut game = GameState::new(FighterId::Kael, FighterId::Kael);
-        game.phase = GamePhase::Fighting;
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
 
         game.fighters[0].physics.position = Vec3::new(0.0, 0.0, 0.0);
         game.fighters[1].physics.position = Vec3::new(1.5, 0.0, 0.0);
@@ -767,8 +1852,7 @@ mod tests {
         let blocked_health = game.fighters[1].health;
 
         // Reset for unblocked
-        let mut game2 = GameState::new(FighterId::Kael, FighterId::Kael);
-        game2.phase = GamePhase::Fighting;
+        let mut game2 = fighting(FighterId::Kael, FighterId::Kael);
         game2.fighters[0].physics.position = Vec3::new(0.0, 0.0, 0.0);
         game2.fighters[1].physics.position = Vec3::new(1.5, 0.0, 0.0);
 
@@ -783,10 +1867,1259 @@ mod tests {
         assert!(blocked_health > unblocked_health);
     }
 
+    /// Put `idx` into the active frames of `attack` right away.
+    fn force_active_attack(game: &mut GameState, idx: usize, attack: ActiveAttack) {
+        let sm = &mut game.fighters[idx].state_machine;
+        sm.start_attack(attack, 0, 5, 5);
+        sm.tick();
+        assert!(sm.is_attack_active());
+    }
+
+    fn juggle_setup() -> GameState {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].physics.position = Vec3::new(0.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(1.2, 0.6, 0.0);
+        game.fighters[1].physics.grounded = false;
+        game.fighters[1].state_machine.enter_airborne();
+        game
+    }
+
     #[test]
-    fn round_ends_on_ko() {
-        let mut game = GameState::new(FighterId::Kael, FighterId::Knight);
+    fn airborne_hit_keeps_juggle_going() {
+        let mut game = juggle_setup();
+        let health = game.fighters[1].health;
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+
+        assert!(game.fighters[1].health < health);
+        assert_eq!(game.fighters[1].state_machine.state, FighterState::Airborne);
+        assert_eq!(
+            game.fighters[1].juggle_points_taken,
+            game.fighters[0].data.moveset.light_attack.juggle_points
+        );
+    }
+
+    #[test]
+    fn grounded_only_attack_whiffs_on_airborne() {
+        let mut game = juggle_setup();
+        let health = game.fighters[1].health;
+        force_active_attack(&mut game, 0, ActiveAttack::LowKick);
+        game.check_combat();
+        assert_eq!(game.fighters[1].health, health);
+    }
+
+    #[test]
+    fn exhausted_juggle_budget_lets_defender_fall() {
+        let mut game = juggle_setup();
+        game.fighters[1].juggle_points_taken = JUGGLE_BUDGET;
+        let health = game.fighters[1].health;
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert_eq!(game.fighters[1].health, health);
+    }
+
+    #[test]
+    fn otg_attack_hits_knocked_down_fighter() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        game.fighters[1]
+            .state_machine
+            .enter_knockdown(30, KnockdownKind::Soft);
+
+        // A normal punch cannot hit a downed opponent...
+        let health = game.fighters[1].health;
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert_eq!(game.fighters[1].health, health);
+
+        // ...but an OTG-capable low kick can
+        game.fighters[0].state_machine = StateMachine::new();
+        force_active_attack(&mut game, 0, ActiveAttack::LowKick);
+        game.check_combat();
+        assert!(game.fighters[1].health < health);
+        assert_eq!(
+            game.fighters[1].state_machine.state,
+            FighterState::Knockdown
+        );
+        // The hit adds downtime on top of what was left and keeps the knockdown kind
+        let sm = &game.fighters[1].state_machine;
+        assert_eq!(
+            sm.total_frames - sm.frame_counter,
+            30 + OTG_KNOCKDOWN_FRAMES
+        );
+        assert_eq!(sm.knockdown_kind, KnockdownKind::Soft);
+    }
+
+    #[test]
+    fn grounded_hitstun_loops_spend_the_juggle_budget() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].state_machine.enter_hitstun(20);
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        let light = game.fighters[0].data.moveset.light_attack.juggle_points;
+        assert_eq!(game.fighters[1].juggle_points_taken, light);
+        assert_eq!(game.fighters[1].state_machine.state, FighterState::HitStun);
+
+        // Once the budget is spent the loop breaks into a knockdown
+        game.fighters[0].state_machine = StateMachine::new();
+        game.fighters[1].juggle_points_taken = JUGGLE_BUDGET - light;
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        let defender = &game.fighters[1];
+        assert_eq!(defender.state_machine.state, FighterState::Knockdown);
+        assert_eq!(defender.state_machine.knockdown_kind, KnockdownKind::Soft);
+        assert!(defender.physics.knockback.x > 0.0);
+    }
+
+    #[test]
+    fn otg_hit_keeps_a_swept_fighter_swept() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].state_machine.enter_swept();
+        let before = game.fighters[1].state_machine.total_frames;
+        force_active_attack(&mut game, 0, ActiveAttack::LowKick);
+        game.check_combat();
+        let sm = &game.fighters[1].state_machine;
+        assert_eq!(sm.state, FighterState::Swept);
+        assert_eq!(sm.total_frames, before + OTG_KNOCKDOWN_FRAMES);
+    }
+
+    #[test]
+    fn back_tech_roll_moves_away() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].physics.position = Vec3::new(-3.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(3.0, 0.0, 0.0);
+        game.fighters[1]
+            .state_machine
+            .enter_knockdown(30, KnockdownKind::Soft);
+
+        let mut roll = empty_input();
+        roll.dash = true;
+        roll.move_back = true;
+        game.tick(&empty_input(), &roll);
+        for _ in 0..(crate::state_machine::TECH_MIN_DOWN_FRAMES + TECH_ROLL_FRAMES) {
+            game.tick(&empty_input(), &empty_input());
+        }
+
+        let travelled = game.fighters[1].physics.position.x - 3.0;
+        let expected = game.fighters[1].data.tech_roll_distance;
+        assert!((travelled - expected).abs() < 0.1, "rolled {travelled}");
+        assert_eq!(game.fighters[1].state_machine.state, FighterState::Idle);
+    }
+
+    #[test]
+    fn ko_fighter_cannot_wake_up() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].health = 0.0;
+        game.fighters[1]
+            .state_machine
+            .enter_knockdown(9999, KnockdownKind::Soft);
+
+        let mut quick_rise = empty_input();
+        quick_rise.dash = true;
+        game.process_input(1, &quick_rise);
+        assert_eq!(game.fighters[1].state_machine.wakeup, None);
+    }
+
+    #[test]
+    fn hitstop_freezes_both_fighters() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        force_active_attack(&mut game, 0, ActiveAttack::Heavy);
+        game.check_combat();
+
+        let stop = game.fighters[0].hitstop;
+        assert!(stop > 0);
+        assert_eq!(game.fighters[1].hitstop, stop);
+
+        let attacker_frame = game.fighters[0].state_machine.frame_counter;
+        let defender_frame = game.fighters[1].state_machine.frame_counter;
+        let defender_x = game.fighters[1].physics.position.x;
+        for _ in 0..stop - 1 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(game.fighters[0].state_machine.frame_counter, attacker_frame);
+        assert_eq!(game.fighters[1].state_machine.frame_counter, defender_frame);
+        assert_eq!(game.fighters[1].physics.position.x, defender_x);
+
+        // Once the freeze ends the knockback carries the defender away
+        game.tick(&empty_input(), &empty_input());
+        game.tick(&empty_input(), &empty_input());
+        assert_eq!(game.fighters[0].hitstop, 0);
+        assert!(game.fighters[1].physics.position.x > defender_x);
+    }
+
+    #[test]
+    fn inputs_buffer_during_hitstop() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].hitstop = 10;
+
+        let mut light = empty_input();
+        light.light_attack = true;
+        for _ in 0..3 {
+            game.tick(&light, &empty_input());
+        }
+        assert_eq!(
+            game.fighters[0].input_buffer.detect_combo(),
+            Some(ComboType::ThreeHit)
+        );
+        assert_eq!(game.fighters[0].state_machine.state, FighterState::Idle);
+    }
+
+    #[test]
+    fn buffered_inputs_survive_the_longest_hitstop() {
+        let longest = FighterId::ALL
+            .iter()
+            .flat_map(|&id| {
+                let data = FighterData::get(id);
+                let moves = &data.moveset;
+                [
+                    &moves.light_attack,
+                    &moves.heavy_attack,
+                    &moves.special_attack,
+                    &moves.mid_kick,
+                    &moves.low_kick,
+                    &moves.aerial,
+                    &moves.combo_finisher,
+                    &moves.super_attack,
+                ]
+                .into_iter()
+                .chain(data.command_normals.iter().map(|c| &c.attack))
+                .chain(
+                    data.stances
+                        .iter()
+                        .flat_map(|s| s.moves.iter().map(|m| &m.attack)),
+                )
+            })
+            .map(combat::hitstop_frames)
+            .max()
+            .unwrap();
+
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].input_buffer.push(InputAction::LightAttack);
+        game.fighters[0].input_buffer.push(InputAction::LightAttack);
+        // Age the inputs until they're about to expire, then freeze
+        for _ in 0..game.fighters[0].input_buffer.expiry_frames() - 1 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        game.fighters[0].hitstop = longest;
+        for _ in 0..longest {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(game.fighters[0].hitstop, 0);
+
+        game.fighters[0].input_buffer.push(InputAction::LightAttack);
+        assert_eq!(
+            game.fighters[0].input_buffer.detect_combo(),
+            Some(ComboType::ThreeHit)
+        );
+    }
+
+    #[test]
+    fn hits_build_meter_for_both_fighters() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        force_active_attack(&mut game, 0, ActiveAttack::Heavy);
+        game.check_combat();
+
+        let damage = game.fighters[1].data.max_health - game.fighters[1].health;
+        assert!((game.fighters[0].meter - damage * METER_GAIN_DEALT).abs() < 0.01);
+        assert!((game.fighters[1].meter - damage * METER_GAIN_TAKEN).abs() < 0.01);
+    }
+
+    #[test]
+    fn super_needs_a_full_bar() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        let combo = |game: &mut GameState| {
+            for press in [
+                InputAction::LightAttack,
+                InputAction::HeavyAttack,
+                InputAction::Special,
+            ] {
+                game.fighters[0].input_buffer.push(press);
+            }
+        };
+
+        game.fighters[0].meter = SUPER_METER_COST - 1.0;
+        combo(&mut game);
+        game.process_input(0, &empty_input());
+        assert_ne!(
+            game.fighters[0].state_machine.attack,
+            Some(ActiveAttack::Super)
+        );
+
+        game.fighters[0].meter = SUPER_METER_COST + 10.0;
+        game.process_input(0, &empty_input());
+        assert_eq!(
+            game.fighters[0].state_machine.attack,
+            Some(ActiveAttack::Super)
+        );
+        assert!((game.fighters[0].meter - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn enhanced_special_spends_meter() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].meter = METER_PER_BAR;
+
+        let mut input = empty_input();
+        input.heavy_attack = true;
+        input.special = true;
+        game.process_input(0, &input);
+        assert_eq!(
+            game.fighters[0].state_machine.attack,
+            Some(ActiveAttack::EnhancedSpecial)
+        );
+        assert_eq!(
+            game.fighters[0].meter,
+            METER_PER_BAR - ENHANCED_SPECIAL_METER_COST
+        );
+    }
+
+    #[test]
+    fn meter_carryover_rule() {
+        for (rule, expected) in [(MeterCarryover::Keep, 150.0), (MeterCarryover::Reset, 0.0)] {
+            let config = MatchConfig {
+                meter_carryover: rule,
+                ..MatchConfig::default()
+            };
+            let mut game = GameState::new(FighterId::Kael, FighterId::Knight, config).unwrap();
+            game.phase = GamePhase::Fighting;
+            game.fighters[0].meter = 150.0;
+            game.fighters[1].health = 0.0;
+            for _ in 0..130 {
+                game.tick(&empty_input(), &empty_input());
+            }
+            assert_eq!(game.phase, GamePhase::Countdown);
+            assert_eq!(game.fighters[0].meter, expected);
+        }
+    }
+
+    #[test]
+    fn running_out_of_stamina_exhausts() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].physics.position = Vec3::new(-3.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(3.0, 0.0, 0.0);
+        game.fighters[0].stamina = game.fighters[0].data.dash.stamina_cost;
+
+        let mut dash = empty_input();
+        dash.dash = true;
+        game.tick(&dash, &empty_input());
+        assert!(game.fighters[0].is_exhausted());
+        assert!(game.events.contains(&GameEvent::Exhausted { player: 0 }));
+
+        // No regen while exhausted, even once back in neutral
+        for _ in 1..EXHAUSTED_COOLDOWN_FRAMES {
+            game.tick(&empty_input(), &empty_input());
+            assert_eq!(game.fighters[0].stamina, 0.0);
+        }
+        game.tick(&empty_input(), &empty_input());
+        assert!(!game.fighters[0].is_exhausted());
+        assert!(game.events.contains(&GameEvent::Recovered { player: 0 }));
+        let max_stamina = game.fighters[0].data.max_stamina;
+        assert_eq!(
+            game.fighters[0].stamina,
+            max_stamina * EXHAUSTED_RECOVERY_STAMINA
+        );
+
+        game.tick(&empty_input(), &empty_input());
+        assert!(game.fighters[0].stamina > max_stamina * EXHAUSTED_RECOVERY_STAMINA);
+    }
+
+    #[test]
+    fn recovering_while_stuck_does_not_exhaust_again() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].physics.position = Vec3::new(-3.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(3.0, 0.0, 0.0);
+        game.fighters[0].stamina = 0.0;
+        // Held down the whole time, so stamina can't regenerate
+        game.fighters[0]
+            .state_machine
+            .enter_knockdown(EXHAUSTED_COOLDOWN_FRAMES + 60, KnockdownKind::Hard);
+
+        let mut exhausted = 0;
+        let mut recovered = 0;
+        for _ in 0..EXHAUSTED_COOLDOWN_FRAMES + 30 {
+            game.tick(&empty_input(), &empty_input());
+            for event in &game.events {
+                match event {
+                    GameEvent::Exhausted { .. } => exhausted += 1,
+                    GameEvent::Recovered { .. } => recovered += 1,
+                    _ => {}
+                }
+            }
+        }
+        assert_eq!((exhausted, recovered), (1, 1));
+        assert!(!game.fighters[0].is_exhausted());
+    }
+
+    #[test]
+    fn exhaustion_slows_movement_and_startup() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        let fresh_startup = game.fighters[0].startup_frames(ActiveAttack::Light);
+
+        game.fighters[0].exhausted_timer = EXHAUSTED_COOLDOWN_FRAMES;
+        assert!(game.fighters[0].startup_frames(ActiveAttack::Light) > fresh_startup);
+
+        let mut walk = empty_input();
+        walk.move_forward = true;
+        game.process_input(0, &walk);
+        let expected = game.fighters[0].data.move_speed * EXHAUSTED_MOVE_SPEED;
+        assert!((game.fighters[0].physics.velocity.x - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn aggressive_style_gains_more_meter_advancing() {
+        let hit = |advancing: bool| {
+            let mut game = close_range(FighterId::Kael, FighterId::Knight);
+            game.fighters[0].advancing = advancing;
+            force_active_attack(&mut game, 0, ActiveAttack::Light);
+            game.check_combat();
+            game.fighters[0].meter
+        };
+        let style = FighterStyle::Aggressive.modifiers();
+        let expected = style.advancing_damage * style.advancing_meter;
+        assert!((hit(true) / hit(false) - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn defensive_style_blocks_for_less_stamina() {
+        let mut game = close_range(FighterId::Kael, FighterId::Knight);
+        game.fighters[1].state_machine.start_block();
+        let (health, stamina) = (game.fighters[1].health, game.fighters[1].stamina);
+
+        force_active_attack(&mut game, 0, ActiveAttack::Heavy);
+        game.check_combat();
+        let chip = health - game.fighters[1].health;
+        let full_drain = BLOCK_STAMINA_COST + chip * BLOCK_STAMINA_PER_DAMAGE;
+        let drain = stamina - game.fighters[1].stamina;
+        let block_stamina = FighterStyle::Defensive.modifiers().block_stamina;
+        assert!((drain - full_drain * block_stamina).abs() < 0.01);
+    }
+
+    #[test]
+    fn technical_style_cancels_into_special() {
+        let cancel = |id: FighterId| {
+            let mut game = fighting(id, FighterId::Knight);
+            let sm = &mut game.fighters[0].state_machine;
+            sm.start_attack(ActiveAttack::Light, 2, 2, 10);
+            for _ in 0..5 {
+                sm.tick();
+            }
+            sm.hit_connected = true;
+            let mut special = empty_input();
+            special.special = true;
+            game.process_input(0, &special);
+            game.fighters[0].state_machine.attack
+        };
+        assert_eq!(
+            FighterData::get(FighterId::Zara).style,
+            FighterStyle::Technical
+        );
+        assert_eq!(cancel(FighterId::Zara), Some(ActiveAttack::Special));
+        assert_eq!(cancel(FighterId::Kael), Some(ActiveAttack::Light));
+    }
+
+    #[test]
+    fn landed_hit_applies_debuff_until_it_expires() {
+        let mut game = close_range(FighterId::Zara, FighterId::Kael);
+        let debuff = game.fighters[0]
+            .data
+            .moveset
+            .special_attack
+            .on_hit_debuff
+            .unwrap();
+
+        force_active_attack(&mut game, 0, ActiveAttack::Special);
+        game.check_combat();
+        let slowed = game.fighters[1].effective_stats().get(debuff.stat);
+        assert!((slowed - (1.0 + debuff.amount)).abs() < 0.001);
+        let slow_startup = game.fighters[1].startup_frames(ActiveAttack::Light);
+
+        // Durations hold during hitstop, so allow for the impact freeze
+        for _ in 0..debuff.frames + game.fighters[1].hitstop {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(
+            game.fighters[1].effective_stats(),
+            game.fighters[1].data.stats
+        );
+        assert!(game.fighters[1].startup_frames(ActiveAttack::Light) < slow_startup);
+    }
+
+    fn status(kind: StatusKind, frames: u32, potency: f32) -> StatusEffect {
+        StatusEffect {
+            kind,
+            frames,
+            potency,
+        }
+    }
+
+    #[test]
+    fn burn_wears_health_down_but_never_kills() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].health = 3.0;
+        game.fighters[1]
+            .status
+            .apply(status(StatusKind::Burn, 200, 1.0));
+        for _ in 0..60 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert!(game.fighters[1].health < 3.0);
+        for _ in 0..140 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(game.fighters[1].health, 1.0);
+        assert!(!game.fighters[1].status.has(StatusKind::Burn));
+    }
+
+    #[test]
+    fn stagger_breaks_block() {
+        let mut game = close_range(FighterId::Knight, FighterId::Kael);
+        game.fighters[1].state_machine.start_block();
+
+        force_active_attack(&mut game, 0, ActiveAttack::Heavy);
+        game.check_combat();
+        assert!(game.fighters[1].status.has(StatusKind::Stagger));
+        assert_ne!(game.fighters[1].state_machine.state, FighterState::Blocking);
+
+        let mut block = empty_input();
+        block.block = true;
+        game.process_input(1, &block);
+        assert_ne!(game.fighters[1].state_machine.state, FighterState::Blocking);
+    }
+
+    #[test]
+    fn stun_prevents_acting() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0]
+            .status
+            .apply(status(StatusKind::Stun, 30, 0.0));
+        let mut light = empty_input();
+        light.light_attack = true;
+        game.process_input(0, &light);
+        assert_eq!(game.fighters[0].state_machine.attack, None);
+
+        for _ in 0..30 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        game.process_input(0, &light);
+        assert_eq!(
+            game.fighters[0].state_machine.attack,
+            Some(ActiveAttack::Light)
+        );
+    }
+
+    /// Put fighter `idx` a few frames into `attack` using its real frame data.
+    fn start_real_attack(game: &mut GameState, idx: usize, attack: ActiveAttack, frames: u32) {
+        let data = game.fighters[idx].get_attack_data(attack).clone();
+        let sm = &mut game.fighters[idx].state_machine;
+        sm.start_attack(
+            attack,
+            data.startup_frames,
+            data.active_frames,
+            data.recovery_frames,
+        );
+        for _ in 0..frames {
+            sm.tick();
+        }
+    }
+
+    fn new_game(p1: FighterId, p2: FighterId) -> GameState {
+        GameState::new(p1, p2, MatchConfig::default()).unwrap()
+    }
+
+    /// A fresh match that has skipped the countdown.
+    fn fighting(p1: FighterId, p2: FighterId) -> GameState {
+        let mut game = new_game(p1, p2);
         game.phase = GamePhase::Fighting;
+        game
+    }
+
+    fn close_range(p1: FighterId, p2: FighterId) -> GameState {
+        let mut game = fighting(p1, p2);
+        game.fighters[0].physics.position = Vec3::new(0.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(1.2, 0.0, 0.0);
+        game
+    }
+
+    #[test]
+    fn super_startup_is_invincible() {
+        let mut game = close_range(FighterId::Knight, FighterId::Kael);
+        start_real_attack(&mut game, 1, ActiveAttack::Super, 2);
+        let health = game.fighters[1].health;
+
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert_eq!(game.fighters[1].health, health);
+        assert!(!game.fighters[0].state_machine.hit_connected);
+    }
+
+    #[test]
+    fn projectile_invincibility_only_stops_projectiles() {
+        let mut game = close_range(FighterId::Zara, FighterId::Knight);
+        start_real_attack(&mut game, 1, ActiveAttack::Special, 2);
+        let health = game.fighters[1].health;
+
+        force_active_attack(&mut game, 0, ActiveAttack::Special);
+        game.check_combat();
+        assert_eq!(game.fighters[1].health, health);
+
+        game.fighters[0].state_machine = StateMachine::new();
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert!(game.fighters[1].health < health);
+    }
+
+    #[test]
+    fn armor_absorbs_hits_without_flinching() {
+        let mut game = close_range(FighterId::Kael, FighterId::Magnus);
+        start_real_attack(&mut game, 1, ActiveAttack::Heavy, 2);
+        let health = game.fighters[1].health;
+
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert!(game.fighters[1].health < health);
+        assert_eq!(
+            game.fighters[1].state_machine.attack,
+            Some(ActiveAttack::Heavy)
+        );
+        assert_eq!(game.fighters[1].armor_hits_absorbed, 1);
+
+        // One-hit armor is spent: the next hit flinches
+        game.fighters[0].state_machine = StateMachine::new();
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert_eq!(game.fighters[1].state_machine.state, FighterState::HitStun);
+    }
+
+    #[test]
+    fn combo_is_reported_when_the_defender_recovers() {
+        let mut game = close_range(FighterId::Knight, FighterId::Kael);
+        let health = game.fighters[1].health;
+        start_real_attack(&mut game, 0, ActiveAttack::ComboFinisher, 0);
+
+        let mut reported = None;
+        for _ in 0..240 {
+            game.tick(&empty_input(), &empty_input());
+            if game.fighters[1].combo.hits() == 2 {
+                assert!(game.fighters[1].combo.current().is_some());
+            }
+            for event in &game.events {
+                if let GameEvent::ComboEnded { attacker, combo } = event {
+                    reported = Some((*attacker, combo.clone()));
+                }
+            }
+        }
+        let (attacker, combo) = reported.expect("combo ended");
+        assert_eq!(attacker, 0);
+        assert_eq!(combo.hits, 2);
+        assert!((combo.damage - (health - game.fighters[1].health)).abs() < 0.001);
+        assert!(combo.scaling < 1.0);
+        assert!(combo.end_frame > combo.start_frame);
+        assert_eq!(game.fighters[1].combo.hits(), 0);
+    }
+
+    #[test]
+    fn stunned_fighters_neither_regenerate_nor_turn() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        // P1 crosses over to P2's other side
+        game.fighters[0].physics.position.x = 2.4;
+        game.fighters[1].stamina = game.fighters[1].data.max_stamina * 0.5;
+        game.fighters[1]
+            .status
+            .apply(status(StatusKind::Stun, 30, 0.0));
+        let stamina = game.fighters[1].stamina;
+
+        for _ in 0..10 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(game.fighters[1].stamina, stamina);
+        assert_eq!(game.fighters[1].facing, Facing::Left);
+    }
+
+    #[test]
+    fn stunned_defender_is_still_in_the_combo() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].state_machine.enter_hitstun(20);
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        game.fighters[0].state_machine = StateMachine::new();
+        game.fighters[0].hitstop = 0;
+        game.fighters[1].hitstop = 0;
+        game.fighters[1]
+            .combo
+            .record_hit(game.frame, "Cross Punch", 1.0, 1.0);
+        game.fighters[1].state_machine = StateMachine::new();
+        game.fighters[1]
+            .status
+            .apply(status(StatusKind::Stun, 30, 0.0));
+        let juggle = game.fighters[1].juggle_points_taken;
+
+        for _ in 0..29 {
+            game.tick(&empty_input(), &empty_input());
+            assert!(!game
+                .events
+                .iter()
+                .any(|e| matches!(e, GameEvent::ComboEnded { .. })));
+        }
+        assert_eq!(game.fighters[1].combo.hits(), 2);
+        assert_eq!(game.fighters[1].juggle_points_taken, juggle);
+
+        // The combo ends once the stun wears off
+        game.tick(&empty_input(), &empty_input());
+        assert!(game
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::ComboEnded { .. })));
+        assert_eq!(game.fighters[1].juggle_points_taken, 0);
+    }
+
+    #[test]
+    fn multi_hit_attack_lands_every_hit() {
+        let mut game = close_range(FighterId::Knight, FighterId::Kael);
+        let finisher = game.fighters[0].data.moveset.combo_finisher.clone();
+        assert_eq!(finisher.hits, 2);
+        start_real_attack(&mut game, 0, ActiveAttack::ComboFinisher, 0);
+
+        let mut drops = 0;
+        let mut health = game.fighters[1].health;
+        for _ in 0..finisher.total_frames() + 30 {
+            game.tick(&empty_input(), &empty_input());
+            if game.fighters[1].health < health {
+                drops += 1;
+                health = game.fighters[1].health;
+                if drops == 1 {
+                    // The first hit only stuns; the knockdown waits for the last
+                    assert_eq!(game.fighters[1].state_machine.state, FighterState::HitStun);
+                }
+            }
+        }
+        assert_eq!(drops, 2);
+        assert_eq!(game.fighters[1].combo.hits(), 2);
+    }
+
+    #[test]
+    fn multi_hit_windows_fit_active_frames() {
+        for id in FighterId::ALL {
+            let moves = &FighterData::get(id).moveset;
+            for attack in [
+                &moves.light_attack,
+                &moves.heavy_attack,
+                &moves.special_attack,
+                &moves.mid_kick,
+                &moves.low_kick,
+                &moves.aerial,
+                &moves.combo_finisher,
+                &moves.super_attack,
+            ] {
+                assert!(attack.hits >= 1, "{} has no hits", attack.name);
+                let needed = (attack.hits - 1) * attack.rehit_interval + 1;
+                assert!(
+                    attack.active_frames >= needed,
+                    "{} can't fit {} hits",
+                    attack.name,
+                    attack.hits
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zara_needs_mana_for_specials() {
+        let mut game = fighting(FighterId::Zara, FighterId::Kael);
+        game.fighters[0].behavior = Box::new(behavior::Mana {
+            mana: 0.0,
+            channeling: false,
+        });
+        let mut special = empty_input();
+        special.special = true;
+        game.process_input(0, &special);
+        assert_ne!(
+            game.fighters[0].state_machine.state,
+            FighterState::Attacking
+        );
+
+        game.fighters[0].behavior.reset_round();
+        game.process_input(0, &special);
+        assert_eq!(
+            game.fighters[0].state_machine.attack,
+            Some(ActiveAttack::Special)
+        );
+    }
+
+    #[test]
+    fn knight_shield_reduces_chip_damage() {
+        let blocked_damage = |shield: f32| {
+            let mut game = close_range(FighterId::Kael, FighterId::Knight);
+            game.fighters[1].behavior = Box::new(behavior::Shield { durability: shield });
+            game.fighters[1].state_machine.start_block();
+            force_active_attack(&mut game, 0, ActiveAttack::Light);
+            game.check_combat();
+            game.fighters[1].data.max_health - game.fighters[1].health
+        };
+        let broken = blocked_damage(0.0);
+        assert!(broken > 0.0);
+        assert!((blocked_damage(100.0) - broken * 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn stance_swaps_moves_until_exited() {
+        let mut game = fighting(FighterId::Orin, FighterId::Kael);
+        let ginga = &game.fighters[0].data.stances[0];
+        let mut stance = empty_input();
+        stance.stance = true;
+
+        game.process_input(0, &stance);
+        assert_eq!(game.fighters[0].current_animation(), ginga.enter_anim);
+        for _ in 0..ginga.enter_frames {
+            game.fighters[0].state_machine.tick();
+        }
+        assert_eq!(game.fighters[0].stance().map(|s| s.name), Some(ginga.name));
+        assert_eq!(game.fighters[0].current_animation(), ginga.idle_anim);
+        let light = game.fighters[0].get_attack_data(ActiveAttack::Light).name;
+        assert_eq!(
+            Some(light),
+            ginga.move_for(ActiveAttack::Light).map(|m| m.name)
+        );
+
+        game.process_input(0, &stance);
+        for _ in 0..ginga.exit_frames {
+            game.fighters[0].state_machine.tick();
+        }
+        assert!(game.fighters[0].stance().is_none());
+        let light = game.fighters[0].get_attack_data(ActiveAttack::Light).name;
+        assert_eq!(light, game.fighters[0].data.moveset.light_attack.name);
+    }
+
+    #[test]
+    fn held_direction_picks_command_normal() {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        let mut input = empty_input();
+        input.light_attack = true;
+        input.move_forward = true;
+        input.move_back = true;
+        // Forward wins over back; Kael has no back + light, so it falls back to the plain light
+        let resolved = game.fighters[0].resolve_normal(ActiveAttack::Light, &input);
+        assert_eq!(
+            game.fighters[0].get_attack_data(resolved).name,
+            "Lunging Cross"
+        );
+        input.move_forward = false;
+        assert_eq!(
+            game.fighters[0].resolve_normal(ActiveAttack::Light, &input),
+            ActiveAttack::Light
+        );
+
+        input.heavy_attack = true;
+        input.light_attack = false;
+        game.process_input(0, &input);
+        let attack = game.fighters[0].state_machine.attack.unwrap();
+        assert_eq!(
+            game.fighters[0].get_attack_data(attack).name,
+            "Rising Elbow"
+        );
+    }
+
+    #[test]
+    fn side_command_normals_follow_facing() {
+        let mut game = new_game(FighterId::Zara, FighterId::Zara);
+        let mut input = empty_input();
+        input.move_left = true;
+        let left = game.fighters[0].resolve_normal(ActiveAttack::Heavy, &input);
+        assert!(matches!(left, ActiveAttack::Command(_)));
+
+        game.fighters[0].facing = Facing::Left;
+        assert_eq!(
+            game.fighters[0].resolve_normal(ActiveAttack::Heavy, &input),
+            ActiveAttack::Heavy
+        );
+    }
+
+    #[test]
+    fn forward_dash_invincibility_matches_the_hit_logic() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        let mut input = empty_input();
+        input.dash = true;
+        game.process_input(1, &input);
+        assert_eq!(game.fighters[1].state_machine.dash_kind, DashKind::Forward);
+
+        let light = game.fighters[0].data.moveset.light_attack.clone();
+        for _ in 1..game.fighters[1].data.dash.frames {
+            game.fighters[1].state_machine.tick();
+            let invulnerable = game.fighters[1].is_invulnerable_to(AttackClass::Strike);
+            assert!(invulnerable);
+            assert_eq!(game.fighters[1].can_be_hit_by(&light, false), !invulnerable);
+
+            let mut attempt = game.clone();
+            let health = attempt.fighters[1].health;
+            force_active_attack(&mut attempt, 0, ActiveAttack::Light);
+            attempt.check_combat();
+            assert_eq!(attempt.fighters[1].health == health, invulnerable);
+        }
+    }
+
+    #[test]
+    fn backdash_retreats_with_early_invincibility() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        let mut input = empty_input();
+        input.dash = true;
+        input.move_back = true;
+        game.process_input(1, &input);
+
+        let fighter = &game.fighters[1];
+        let backdash = fighter.data.backdash;
+        assert_eq!(fighter.state_machine.dash_kind, DashKind::Back);
+        assert!(fighter.physics.velocity.x > 0.0);
+        assert_eq!(
+            fighter.stamina,
+            fighter.data.max_stamina - backdash.stamina_cost
+        );
+
+        let invuln = backdash.invuln.unwrap();
+        while game.fighters[1].state_machine.frame_counter < invuln.start {
+            game.fighters[1].state_machine.tick();
+        }
+        assert!(game.fighters[1].is_invulnerable_to(AttackClass::Strike));
+        while game.fighters[1].state_machine.frame_counter <= invuln.end {
+            game.fighters[1].state_machine.tick();
+        }
+        assert!(!game.fighters[1].is_invulnerable_to(AttackClass::Strike));
+    }
+
+    #[test]
+    fn sidestep_leaves_the_line_and_evades_strikes() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        let mut input = empty_input();
+        input.dash = true;
+        input.move_right = true;
+        game.process_input(1, &input);
+        assert_eq!(game.fighters[1].state_machine.dash_kind, DashKind::Side);
+
+        for _ in 0..game.fighters[1].data.sidestep.invuln.unwrap().start {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert!(game.fighters[1].physics.position.z > 0.0);
+        assert!(game.fighters[0].physics.position.z.abs() < 0.001);
+        assert!(game.fighters[1].is_invulnerable_to(AttackClass::Strike));
+        assert!(!game.fighters[1].is_invulnerable_to(AttackClass::Throw));
+    }
+
+    #[test]
+    fn attacks_follow_their_own_movement_curves() {
+        let travel = |forward: bool, back: bool, heavy: bool| {
+            let mut game = fighting(FighterId::Kael, FighterId::Kael);
+            game.fighters[0].physics.position = Vec3::new(-3.0, 0.0, 0.0);
+            game.fighters[1].physics.position = Vec3::new(3.0, 0.0, 0.0);
+            let mut input = empty_input();
+            input.move_forward = forward;
+            input.move_back = back;
+            input.light_attack = !heavy;
+            input.heavy_attack = heavy;
+            game.tick(&input, &empty_input());
+            for _ in 0..15 {
+                game.tick(&empty_input(), &empty_input());
+            }
+            game.fighters[0].physics.position.x + 3.0
+        };
+        let jab = travel(false, false, false);
+        let lunging_cross = travel(true, false, false);
+        let rising_elbow = travel(false, true, true);
+        assert!(jab > 0.0);
+        assert!(lunging_cross > jab * 2.0, "{lunging_cross} vs {jab}");
+        assert!(rising_elbow < 0.0, "{rising_elbow}");
+    }
+
+    #[test]
+    fn special_costs_its_own_stamina() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        let cost = game.fighters[0].data.moveset.special_attack.stamina_cost;
+        game.fighters[0].stamina = cost - 1.0;
+        let mut input = empty_input();
+        input.special = true;
+        game.process_input(0, &input);
+        assert_ne!(
+            game.fighters[0].state_machine.state,
+            FighterState::Attacking
+        );
+
+        game.fighters[0].stamina = cost;
+        game.process_input(0, &input);
+        assert_eq!(
+            game.fighters[0].state_machine.attack,
+            Some(ActiveAttack::Special)
+        );
+        assert_eq!(game.fighters[0].stamina, 0.0);
+    }
+
+    #[test]
+    fn knockdown_and_hitstun_come_from_the_attack() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        force_active_attack(&mut game, 0, ActiveAttack::LowKick);
+        game.check_combat();
+        let sm = &game.fighters[1].state_machine;
+        assert_eq!(sm.state, FighterState::Swept);
+        assert_eq!(sm.knockdown_kind, KnockdownKind::Sweep);
+
+        let mut game = close_range(FighterId::Knight, FighterId::Kael);
+        force_active_attack(&mut game, 0, ActiveAttack::Special);
+        game.check_combat();
+        let sm = &game.fighters[1].state_machine;
+        assert_eq!(sm.state, FighterState::HitStun);
+        assert_eq!(
+            Some(sm.total_frames),
+            game.fighters[0].data.moveset.special_attack.hitstun_frames
+        );
+    }
+
+    #[test]
+    fn sweeps_use_the_swept_state() {
+        let mut game = close_range(FighterId::Zara, FighterId::Zara);
+        force_active_attack(&mut game, 0, ActiveAttack::LowKick);
+        game.check_combat();
+        let swept = &game.fighters[1];
+        assert_eq!(swept.state_machine.state, FighterState::Swept);
+        assert_eq!(swept.current_animation(), swept.data.animations.sweep_fall);
+        assert!(swept.hurtbox().max.y < swept.data.hurtbox.max.y);
+
+        // Swept fighters can't tech roll away
+        let mut roll = empty_input();
+        roll.dash = true;
+        roll.move_forward = true;
+        game.process_input(1, &roll);
+        assert_eq!(game.fighters[1].state_machine.wakeup, None);
+    }
+
+    #[test]
+    fn only_landed_hits_go_stale() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].physics.position.x = 5.0;
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert!(game.fighters[0].stale.moves().is_empty());
+
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        let light = game.fighters[0].data.moveset.light_attack.name;
+        assert_eq!(game.fighters[0].stale.moves().len(), 1);
+
+        // The next light attack starts out staled by the one that landed
+        game.fighters[0].state_machine = StateMachine::new();
+        game.fighters[0].hitstop = 0;
+        let mut input = empty_input();
+        input.light_attack = true;
+        game.process_input(0, &input);
+        assert_eq!(
+            game.fighters[0].attack_stale,
+            game.fighters[0].stale.multiplier(light)
+        );
+        assert!(game.fighters[0].attack_stale < 1.0);
+
+        game.fighters[0].reset_round(Vec3::ZERO, Facing::Right);
+        assert!(game.fighters[0].stale.moves().is_empty());
+    }
+
+    #[test]
+    fn free_depth_mode_keeps_fighters_off_the_line() {
+        for (mode, stays) in [(DepthMode::Aligned, false), (DepthMode::Free, true)] {
+            let mut game = close_range(FighterId::Kael, FighterId::Kael);
+            game.config.depth_mode = mode;
+            game.fighters[1].physics.position.z = 1.0;
+            for _ in 0..30 {
+                game.tick(&empty_input(), &empty_input());
+            }
+            let z = game.fighters[1].physics.position.z;
+            assert_eq!((z - 1.0).abs() < 0.001, stays, "{mode:?}: z = {z}");
+        }
+    }
+
+    #[test]
+    fn only_tracking_attacks_reach_an_off_line_defender() {
+        // P1 faces right, so +Z is on their right-hand side
+        for (attack, lands) in [
+            (ActiveAttack::Light, false),  // no tracking
+            (ActiveAttack::Heavy, false),  // tracks left
+            (ActiveAttack::MidKick, true), // tracks right
+            (ActiveAttack::LowKick, true), // tracks both ways
+        ] {
+            let mut game = close_range(FighterId::Kael, FighterId::Kael);
+            game.config.depth_mode = DepthMode::Free;
+            game.fighters[1].physics.position.z = 1.2;
+            let health = game.fighters[1].health;
+            force_active_attack(&mut game, 0, attack);
+            game.check_combat();
+            assert_eq!(game.fighters[1].health < health, lands, "{attack:?}");
+        }
+    }
+
+    #[test]
+    fn tracking_attacks_catch_a_sidestep() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        let mut input = empty_input();
+        input.dash = true;
+        input.move_left = true;
+        game.process_input(1, &input);
+        for _ in 0..game.fighters[1].data.sidestep.invuln.unwrap().start {
+            game.tick(&empty_input(), &empty_input());
+        }
+        // P2 stepped to -Z, which is P1's left
+        assert_eq!(
+            game.fighters[0].side_of(game.fighters[1].physics.position),
+            Some(Direction::Left)
+        );
+        let data = &game.fighters[0].data.moveset;
+        assert!(!game.fighters[1].can_be_hit_by(&data.light_attack, false));
+        assert!(game.fighters[1].can_be_hit_by(&data.heavy_attack, true));
+    }
+
+    #[test]
+    fn hit_event_reports_damage_breakdown() {
+        let mut game = close_range(FighterId::Knight, FighterId::Knight);
+        let health = game.fighters[1].health;
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+
+        let breakdown = game
+            .events
+            .iter()
+            .find_map(|e| match e {
+                GameEvent::Hit { breakdown, .. } => Some(*breakdown),
+                _ => None,
+            })
+            .expect("hit event");
+        assert_eq!(breakdown.damage_type, DamageType::Slash);
+        assert_eq!(
+            breakdown.resistance,
+            game.fighters[1].data.resistances.slash
+        );
+        assert!((health - game.fighters[1].health - breakdown.total).abs() < 0.001);
+    }
+
+    /// Both fighters' attacks active on the same frame, standing close enough that
+    /// each hitbox reaches the other's body without the hitboxes meeting.
+    fn simultaneous_attacks(p1: ActiveAttack, p2: ActiveAttack) -> GameState {
+        let mut game = fighting(FighterId::Kael, FighterId::Kael);
+        game.fighters[0].physics.position = Vec3::new(0.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(0.6, 0.0, 0.0);
+        game.fighters[1].facing = Facing::Left;
+        force_active_attack(&mut game, 0, p1);
+        force_active_attack(&mut game, 1, p2);
+        game
+    }
+
+    #[test]
+    fn simultaneous_hits_trade_regardless_of_player_order() {
+        for (p1, p2) in [
+            (ActiveAttack::Light, ActiveAttack::MidKick),
+            (ActiveAttack::MidKick, ActiveAttack::Light),
+        ] {
+            let mut game = simultaneous_attacks(p1, p2);
+            let max_health = game.fighters[0].data.max_health;
+            game.check_combat();
+
+            assert!(game.events.contains(&GameEvent::Trade));
+            for fighter in &game.fighters {
+                assert!(fighter.health < max_health);
+                assert_eq!(fighter.state_machine.state, FighterState::HitStun);
+            }
+        }
+    }
+
+    #[test]
+    fn trade_damage_does_not_depend_on_player_slot() {
+        let mut game = simultaneous_attacks(ActiveAttack::Light, ActiveAttack::Light);
+        game.check_combat();
+        assert_eq!(game.fighters[0].health, game.fighters[1].health);
+    }
+
+    #[test]
+    fn meeting_hitboxes_clash() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].facing = Facing::Left;
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        force_active_attack(&mut game, 1, ActiveAttack::Light);
+        game.check_combat();
+
+        assert!(game.events.contains(&GameEvent::Clash));
+        for fighter in &game.fighters {
+            assert_eq!(fighter.health, fighter.data.max_health);
+            assert_eq!(fighter.state_machine.attack, None);
+        }
+        assert!(game.fighters[0].physics.knockback.x < 0.0);
+        assert!(game.fighters[1].physics.knockback.x > 0.0);
+    }
+
+    #[test]
+    fn blocking_drains_stamina() {
+        let mut game = close_range(FighterId::Kael, FighterId::Kael);
+        game.fighters[1].state_machine.start_block();
+        let stamina = game.fighters[1].stamina;
+
+        force_active_attack(&mut game, 0, ActiveAttack::Heavy);
+        game.check_combat();
+        assert!(game.fighters[1].stamina <= stamina - BLOCK_STAMINA_COST);
+    }
+
+    #[test]
+    fn disarming_attack_drops_weapon_for_pickup() {
+        let mut game = close_range(FighterId::Kael, FighterId::Knight);
+        assert!(game.fighters[0].data.moveset.special_attack.disarms);
+
+        force_active_attack(&mut game, 0, ActiveAttack::Special);
+        game.check_combat();
+        assert!(game.fighters[1].is_disarmed());
+        assert_eq!(game.fighters[1].weapon.weapon_type, WeaponType::Unarmed);
+        assert_eq!(game.items.len(), 1);
+        assert!(game.events.contains(&GameEvent::Disarmed {
+            player: 1,
+            weapon: WeaponType::SwordAndShield,
+        }));
+
+        // Let the sword land, then Kael walks over and takes it
+        for _ in 0..120 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert!(game.items[0].physics.grounded);
+        game.fighters[0].state_machine = StateMachine::new();
+        game.fighters[0].physics.position = game.items[0].physics.position;
+        let mut pickup = empty_input();
+        pickup.pickup = true;
+        game.tick(&pickup, &empty_input());
+        assert!(game.items.is_empty());
+        assert_eq!(
+            game.fighters[0].weapon.weapon_type,
+            WeaponType::SwordAndShield
+        );
+        // Kael started unarmed; holding a stage weapon doesn't make them disarmed
+        assert!(!game.fighters[0].is_disarmed());
+        assert!(game.fighters[1].is_disarmed());
+    }
+
+    #[test]
+    fn guard_break_disarms() {
+        let mut game = close_range(FighterId::Kael, FighterId::Knight);
+        game.fighters[1].state_machine.start_block();
+        game.fighters[1].stamina = 1.0;
+
+        force_active_attack(&mut game, 0, ActiveAttack::Light);
+        game.check_combat();
+        assert_eq!(game.fighters[1].state_machine.state, FighterState::HitStun);
+        assert!(game.events.contains(&GameEvent::GuardBreak { player: 1 }));
+        assert!(game.fighters[1].is_disarmed());
+    }
+
+    #[test]
+    fn new_round_restores_weapons() {
+        let mut game = fighting(FighterId::Kael, FighterId::Knight);
+        game.disarm(1, 0.0);
+        game.fighters[1].health = 0.0;
+        for _ in 0..130 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert!(game.items.is_empty());
+        assert!(!game.fighters[1].is_disarmed());
+    }
+
+    #[test]
+    fn round_ends_on_ko() {
+        let mut game = fighting(FighterId::Kael, FighterId::Knight);
         game.fighters[1].health = 0.0;
 
         game.tick(&empty_input(), &empty_input());
@@ -796,9 +3129,8 @@ mod tests {
 
     #[test]
     fn round_ends_on_timer() {
-        let mut game = GameState::new(FighterId::Kael, FighterId::Knight);
-        game.phase = GamePhase::Fighting;
-        game.round_timer = 1;
+        let mut game = fighting(FighterId::Kael, FighterId::Knight);
+        game.round_timer = Some(1);
         game.fighters[0].health = 80.0;
         game.fighters[1].health = 50.0;
 
@@ -806,12 +3138,56 @@ mod tests {
         assert_eq!(game.phase, GamePhase::RoundOver);
         // P1 has more health, should win
         assert_eq!(game.fighters[0].round_wins, 1);
+        assert_eq!(
+            game.round_result.map(|r| r.reason),
+            Some(RoundEndReason::TimeUp)
+        );
     }
 
     #[test]
-    fn match_ends_after_enough_round_wins() {
-        let mut game = GameState::new(FighterId::Kael, FighterId::Knight);
+    fn ring_out_ends_round() {
+        let mut game = new_game(FighterId::Kael, FighterId::Knight);
+        game.set_stage_edge(StageEdge::Open);
         game.phase = GamePhase::Fighting;
+        game.fighters[0].physics.position = Vec3::new(8.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(9.9, 0.0, 0.0);
+        game.fighters[1]
+            .physics
+            .apply_knockback(Vec3::new(20.0, 0.0, 0.0));
+
+        game.tick(&empty_input(), &empty_input());
+        assert_eq!(game.fighters[1].state_machine.state, FighterState::Falling);
+        assert!(game.events.contains(&GameEvent::RingOut { player: 1 }));
+        assert_eq!(game.phase, GamePhase::RoundOver);
+        assert_eq!(game.fighters[0].round_wins, 1);
+        assert_eq!(
+            game.round_result,
+            Some(RoundResult {
+                winner: Some(0),
+                reason: RoundEndReason::RingOut,
+            })
+        );
+    }
+
+    #[test]
+    fn walled_stage_has_no_ring_out() {
+        let mut game = fighting(FighterId::Kael, FighterId::Knight);
+        game.fighters[0].physics.position = Vec3::new(8.0, 0.0, 0.0);
+        game.fighters[1].physics.position = Vec3::new(9.9, 0.0, 0.0);
+        game.fighters[1]
+            .physics
+            .apply_knockback(Vec3::new(20.0, 0.0, 0.0));
+
+        for _ in 0..30 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(game.phase, GamePhase::Fighting);
+        assert!(!game.fighters[1].physics.is_past_edge());
+    }
+
+    #[test]
+    fn match_ends_after_enough_round_wins() {
+        let mut game = fighting(FighterId::Kael, FighterId::Knight);
         game.fighters[0].round_wins = 1; // Already won 1
         game.fighters[1].health = 0.0;
 
@@ -825,4 +3201,85 @@ mod tests {
         }
         assert_eq!(game.phase, GamePhase::MatchOver);
     }
+
+    #[test]
+    fn match_config_sets_rounds_and_timers() {
+        let config = MatchConfig {
+            rounds: 5,
+            round_time: None,
+            round_over_frames: 10,
+            ..MatchConfig::default()
+        };
+        let mut game = GameState::new(FighterId::Kael, FighterId::Knight, config).unwrap();
+        assert_eq!(game.round_time_remaining(), None);
+        game.phase = GamePhase::Fighting;
+        game.fighters[0].round_wins = 2;
+        game.fighters[1].health = 0.0;
+
+        game.tick(&empty_input(), &empty_input());
+        for _ in 0..11 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        // Best of 5 needs a third win
+        assert_eq!(game.fighters[0].round_wins, 3);
+        assert_eq!(game.phase, GamePhase::MatchOver);
+    }
+
+    #[test]
+    fn untimed_rounds_never_time_out() {
+        let config = MatchConfig {
+            round_time: None,
+            ..MatchConfig::default()
+        };
+        let mut game = GameState::new(FighterId::Kael, FighterId::Knight, config).unwrap();
+        game.phase = GamePhase::Fighting;
+        for _ in 0..60 * 120 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(game.phase, GamePhase::Fighting);
+    }
+
+    #[test]
+    fn handicaps_and_starting_resources_apply_each_round() {
+        let config = MatchConfig {
+            start_health: 0.5,
+            start_stamina: 0.0,
+            handicaps: [
+                Handicap::NONE,
+                Handicap {
+                    health: 0.5,
+                    damage: 2.0,
+                },
+            ],
+            ..MatchConfig::default()
+        };
+        let mut game = GameState::new(FighterId::Kael, FighterId::Kael, config).unwrap();
+        let max_health = game.fighters[0].data.max_health;
+        assert_eq!(game.fighters[0].health, max_health * 0.5);
+        assert_eq!(game.fighters[1].health, max_health * 0.25);
+        assert_eq!(game.fighters[0].stamina, 0.0);
+
+        // P2 hits twice as hard with the same move
+        let mut damage = [0.0; 2];
+        for (attacker, dealt) in damage.iter_mut().enumerate() {
+            let mut game = close_range(FighterId::Kael, FighterId::Kael);
+            game.config = config;
+            let defender = 1 - attacker;
+            let before = game.fighters[defender].health;
+            force_active_attack(&mut game, attacker, ActiveAttack::Light);
+            game.check_combat();
+            *dealt = before - game.fighters[defender].health;
+        }
+        assert!(damage[0] > 0.0);
+        assert!((damage[1] - damage[0] * 2.0).abs() < 0.001);
+
+        // Starting amounts come back at the next round
+        game.phase = GamePhase::Fighting;
+        game.fighters[0].health = 0.0;
+        for _ in 0..=config.round_over_frames + 1 {
+            game.tick(&empty_input(), &empty_input());
+        }
+        assert_eq!(game.current_round, 2);
+        assert_eq!(game.fighters[1].health, max_health * 0.25);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::types::Facing;
 use std::collections::VecDeque;
 
 const MAX_BUFFER_SIZE: usize = 10;
@@ -28,8 +29,39 @@ pub struct InputEvent {
 /// Detected combo from the input buffer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ComboType {
-    ThreeHit,     // Light x3
-    Super,        // Light, Heavy, Special
+    ThreeHit, // Light x3
+    Super,    // Light, Heavy, Special
+}
+
+/// A held direction relative to the fighter: forward is toward the opponent,
+/// left and right are the fighter's own sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Forward,
+    Back,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Order in which held directions are tried when several are held at once.
+    pub const PRIORITY: [Direction; 4] = [
+        Direction::Forward,
+        Direction::Back,
+        Direction::Left,
+        Direction::Right,
+    ];
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Forward => write!(f, "Forward"),
+            Direction::Back => write!(f, "Back"),
+            Direction::Left => write!(f, "Left"),
+            Direction::Right => write!(f, "Right"),
+        }
+    }
 }
 
 /// Per-player input state: which directions/buttons are held this frame.
@@ -47,32 +79,74 @@ pub struct InputState {
     pub mid_kick: bool,
     pub low_kick: bool,
     pub aerial: bool,
+    pub pickup: bool,
+    pub stance: bool,
 }
 
 impl InputState {
     pub fn has_movement(&self) -> bool {
         self.move_forward || self.move_back || self.move_left || self.move_right
     }
+
+    /// Whether `direction` is held by a fighter facing `facing`. Forward and back
+    /// already follow the opponent; depth inputs flip when facing left.
+    pub fn holds(&self, direction: Direction, facing: Facing) -> bool {
+        let (own_left, own_right) = match facing {
+            Facing::Right => (self.move_left, self.move_right),
+            Facing::Left => (self.move_right, self.move_left),
+        };
+        match direction {
+            Direction::Forward => self.move_forward,
+            Direction::Back => self.move_back,
+            Direction::Left => own_left,
+            Direction::Right => own_right,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct InputBuffer {
     events: VecDeque<InputEvent>,
     current_frame: u32,
+    leniency: u32, // extra frames before inputs expire
+}
+
+impl Default for InputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InputBuffer {
     pub fn new() -> Self {
+        Self::with_leniency(0)
+    }
+
+    /// A buffer that keeps inputs `leniency` frames longer for combo detection.
+    pub fn with_leniency(leniency: u32) -> Self {
         Self {
             events: VecDeque::new(),
             current_frame: 0,
+            leniency,
         }
     }
 
-    pub fn set_frame(&mut self, frame: u32) {
+    /// How many frames a buffered input stays usable.
+    pub fn expiry_frames(&self) -> u32 {
+        INPUT_EXPIRY_FRAMES + self.leniency
+    }
+
+    /// Jump the buffer's clock straight to `frame`. The game only ever uses `tick`.
+    #[cfg(test)]
+    fn set_frame(&mut self, frame: u32) {
         self.current_frame = frame;
     }
 
+    /// Advance the buffer's clock by one frame.
+    pub fn tick(&mut self) {
+        self.current_frame += 1;
+    }
+
     /// Record an input action.
     pub fn push(&mut self, action: InputAction) {
         // Only buffer attack inputs for combo detection
@@ -93,7 +167,7 @@ impl InputBuffer {
     /// Remove expired inputs from the buffer.
     pub fn expire_old(&mut self) {
         while let Some(front) = self.events.front() {
-            if self.current_frame.saturating_sub(front.frame) > INPUT_EXPIRY_FRAMES {
+            if self.current_frame.saturating_sub(front.frame) > self.expiry_frames() {
                 self.events.pop_front();
             } else {
                 break;
@@ -106,7 +180,7 @@ impl InputBuffer {
         let valid: Vec<_> = self
             .events
             .iter()
-            .filter(|e| self.current_frame.saturating_sub(e.frame) <= INPUT_EXPIRY_FRAMES)
+            .filter(|e| self.current_frame.saturating_sub(e.frame) <= self.expiry_frames())
             .collect();
 
         if valid.len() < 3 {
@@ -196,6 +270,23 @@ mod tests {
         assert_eq!(buf.detect_combo(), None);
     }
 
+    #[test]
+    fn leniency_extends_combo_window() {
+        let presses = |buf: &mut InputBuffer| {
+            for frame in [0, 40, 75] {
+                buf.set_frame(frame);
+                buf.push(InputAction::LightAttack);
+            }
+            buf.expire_old();
+            buf.detect_combo()
+        };
+        assert_eq!(presses(&mut InputBuffer::new()), None);
+        assert_eq!(
+            presses(&mut InputBuffer::with_leniency(20)),
+            Some(ComboType::ThreeHit)
+        );
+    }
+
     #[test]
     fn buffer_max_size() {
         let mut buf = InputBuffer::new();
@@ -213,4 +304,15 @@ mod tests {
         state.move_forward = true;
         assert!(state.has_movement());
     }
+
+    #[test]
+    fn side_directions_follow_facing() {
+        let input = InputState {
+            move_left: true,
+            ..Default::default()
+        };
+        assert!(input.holds(Direction::Left, Facing::Right));
+        assert!(input.holds(Direction::Right, Facing::Left));
+        assert!(!input.holds(Direction::Forward, Facing::Right));
+    }
 }
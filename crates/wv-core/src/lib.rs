@@ -1,8 +1,15 @@
-pub mod types;
-pub mod state_machine;
+pub mod behavior;
 pub mod combat;
-pub mod physics;
+pub mod combo;
+pub mod config;
 pub mod fighter;
-pub mod weapon;
-pub mod input;
 pub mod game;
+pub mod input;
+pub mod physics;
+pub mod rules;
+pub mod stale;
+pub mod state_machine;
+pub mod stats;
+pub mod status;
+pub mod types;
+pub mod weapon;
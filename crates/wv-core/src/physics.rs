@@ -11,12 +11,22 @@ pub const ARENA_MIN_Z: f32 = -3.0;
 pub const ARENA_MAX_Z: f32 = 3.0;
 pub const GROUND_Y: f32 = 0.0;
 
+/// How the x-axis edges of the stage treat fighters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageEdge {
+    /// Invisible walls keep fighters inside the arena.
+    Walled,
+    /// No walls: knockback can carry a fighter over the edge (ring-out).
+    Open,
+}
+
 #[derive(Debug, Clone)]
 pub struct PhysicsBody {
     pub position: Vec3,
     pub velocity: Vec3,
     pub knockback: Vec3,
     pub grounded: bool,
+    pub edge: StageEdge,
 }
 
 impl PhysicsBody {
@@ -26,9 +36,23 @@ impl PhysicsBody {
             velocity: Vec3::ZERO,
             knockback: Vec3::ZERO,
             grounded: true,
+            edge: StageEdge::Walled,
         }
     }
 
+    /// Whether the body is beyond the arena's x bounds (only possible on open stages).
+    pub fn is_past_edge(&self) -> bool {
+        self.position.x < ARENA_MIN_X || self.position.x > ARENA_MAX_X
+    }
+
+    /// Pull the body back inside the arena, unless it has already gone over an open edge.
+    pub fn clamp_to_arena(&mut self) {
+        if self.edge == StageEdge::Walled || !self.is_past_edge() {
+            self.position.x = self.position.x.clamp(ARENA_MIN_X, ARENA_MAX_X);
+        }
+        self.position.z = self.position.z.clamp(ARENA_MIN_Z, ARENA_MAX_Z);
+    }
+
     /// Apply a knockback impulse.
     pub fn apply_knockback(&mut self, force: Vec3) {
         self.knockback = force;
@@ -41,6 +65,7 @@ impl PhysicsBody {
     /// Returns true if the body just landed (was airborne, now grounded).
     pub fn tick(&mut self, dt: f32) -> bool {
         let mut just_landed = false;
+        let was_past_edge = self.is_past_edge();
 
         // Apply gravity before movement so it takes effect this frame
         if !self.grounded {
@@ -57,16 +82,32 @@ impl PhysicsBody {
         let total_vel = self.velocity + self.knockback;
         self.position += total_vel * dt;
 
-        // Ground collision
-        if !self.grounded && self.position.y <= GROUND_Y {
+        // Ground collision (there is no ground past an open edge)
+        if !self.grounded && self.position.y <= GROUND_Y && !self.is_past_edge() {
             self.position.y = GROUND_Y;
             self.velocity.y = 0.0;
             self.grounded = true;
             just_landed = true;
         }
 
-        // Clamp to arena bounds
-        self.position.x = self.position.x.clamp(ARENA_MIN_X, ARENA_MAX_X);
+        // Clamp to arena bounds. On an open stage only knockback can carry a
+        // fighter over the edge; walking is still stopped at the boundary.
+        match self.edge {
+            StageEdge::Walled => {
+                self.position.x = self.position.x.clamp(ARENA_MIN_X, ARENA_MAX_X);
+            }
+            StageEdge::Open => {
+                // Knockback only carries a fighter out over the edge it points at
+                let knocked_out = (self.position.x > ARENA_MAX_X && self.knockback.x > 0.0)
+                    || (self.position.x < ARENA_MIN_X && self.knockback.x < 0.0);
+                if !knocked_out && !was_past_edge {
+                    self.position.x = self.position.x.clamp(ARENA_MIN_X, ARENA_MAX_X);
+                }
+                if self.is_past_edge() {
+                    self.grounded = false;
+                }
+            }
+        }
         self.position.z = self.position.z.clamp(ARENA_MIN_Z, ARENA_MAX_Z);
 
         // Keep on ground if grounded
@@ -142,6 +183,47 @@ mod tests {
         assert!(body.position.x >= ARENA_MIN_X);
     }
 
+    #[test]
+    fn open_edge_stops_walking() {
+        let mut body = PhysicsBody::new(Vec3::new(ARENA_MAX_X - 0.1, 0.0, 0.0));
+        body.edge = StageEdge::Open;
+        body.velocity.x = 1000.0;
+        body.tick(1.0 / 60.0);
+        assert!(!body.is_past_edge());
+        assert!(body.grounded);
+    }
+
+    #[test]
+    fn inward_knockback_does_not_open_the_edge() {
+        let mut body = PhysicsBody::new(Vec3::new(ARENA_MAX_X - 0.1, 0.0, 0.0));
+        body.edge = StageEdge::Open;
+        body.velocity.x = 1000.0;
+        body.apply_knockback(Vec3::new(-1.0, 0.0, 0.0));
+        body.tick(1.0 / 60.0);
+        assert!(!body.is_past_edge());
+        assert!(body.grounded);
+    }
+
+    #[test]
+    fn open_edge_knockback_carries_over() {
+        let mut body = PhysicsBody::new(Vec3::new(ARENA_MAX_X - 0.1, 0.0, 0.0));
+        body.edge = StageEdge::Open;
+        body.apply_knockback(Vec3::new(20.0, 0.0, 0.0));
+        for _ in 0..10 {
+            body.tick(1.0 / 60.0);
+        }
+        assert!(body.is_past_edge());
+        assert!(!body.grounded);
+
+        // Past the edge there is no floor to land on
+        let y_before = body.position.y;
+        for _ in 0..30 {
+            body.tick(1.0 / 60.0);
+        }
+        assert!(body.position.y < y_before);
+        assert!(body.position.y < GROUND_Y);
+    }
+
     #[test]
     fn knockback_decays() {
         let mut body = PhysicsBody::new(Vec3::new(0.0, 0.0, 0.0));
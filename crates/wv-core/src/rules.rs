@@ -0,0 +1,192 @@
+/// How a per-hit factor changes over the course of a combo.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scaling {
+    /// `1 + per_hit * hits`, kept within `min..=max`. A negative `per_hit` scales down.
+    Linear { per_hit: f32, min: f32, max: f32 },
+    /// The factor for each hit in turn (first entry = the combo's first hit).
+    /// Hits past the end of the table keep the last entry.
+    Table(&'static [f32]),
+}
+
+impl Scaling {
+    /// No scaling at all.
+    pub const NONE: Scaling = Scaling::Table(&[1.0]);
+
+    /// The factor for a hit landing after `combo_hits` earlier hits in the combo.
+    pub fn factor(&self, combo_hits: u32) -> f32 {
+        match *self {
+            Scaling::Linear { per_hit, min, max } => {
+                (1.0 + per_hit * combo_hits as f32).clamp(min, max)
+            }
+            Scaling::Table(table) => table
+                .get(combo_hits as usize)
+                .or(table.last())
+                .copied()
+                .unwrap_or(1.0),
+        }
+    }
+}
+
+/// The numbers behind hit resolution: combo scaling, blocking and hitstun.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CombatRules {
+    pub combo_damage: Scaling,
+    pub combo_hitstun: Scaling,
+    pub combo_knockback: Scaling, // pushes fighters apart as a combo goes on
+    pub block_damage: f32,        // fraction of damage a blocked hit still deals
+    pub block_knockback: f32,     // fraction of knockback a blocked hit still deals
+    pub hitstun_frames: u32,      // base hitstun, plus the attack's knockback force
+    pub blockstun_frames: u32,
+}
+
+impl CombatRules {
+    pub const STANDARD: CombatRules = CombatRules {
+        combo_damage: Scaling::Linear {
+            per_hit: -0.15,
+            min: 0.4,
+            max: 1.0,
+        },
+        combo_hitstun: Scaling::Linear {
+            per_hit: -0.25,
+            min: 0.3,
+            max: 1.0,
+        },
+        combo_knockback: Scaling::Linear {
+            per_hit: 0.2,
+            min: 1.0,
+            max: f32::INFINITY,
+        },
+        block_damage: 0.2,
+        block_knockback: 0.3,
+        hitstun_frames: 12,
+        blockstun_frames: 6,
+    };
+
+    /// Fixed damage steps so combo damage is easy to reason about, and less chip.
+    pub const TOURNAMENT: CombatRules = CombatRules {
+        combo_damage: Scaling::Table(&[1.0, 1.0, 0.8, 0.7, 0.6, 0.5, 0.4]),
+        combo_hitstun: Scaling::Table(&[1.0, 0.8, 0.6, 0.5, 0.4, 0.3]),
+        block_damage: 0.1,
+        ..CombatRules::STANDARD
+    };
+
+    /// Long combos stay rewarding and blocking is less safe.
+    pub const ARCADE: CombatRules = CombatRules {
+        combo_damage: Scaling::Linear {
+            per_hit: -0.08,
+            min: 0.6,
+            max: 1.0,
+        },
+        combo_hitstun: Scaling::Linear {
+            per_hit: -0.15,
+            min: 0.5,
+            max: 1.0,
+        },
+        block_damage: 0.3,
+        hitstun_frames: 14,
+        ..CombatRules::STANDARD
+    };
+}
+
+impl Default for CombatRules {
+    fn default() -> Self {
+        CombatRules::STANDARD
+    }
+}
+
+/// The built-in rulesets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RulesPreset {
+    Standard,
+    Tournament,
+    Arcade,
+}
+
+impl RulesPreset {
+    pub const ALL: [RulesPreset; 3] = [
+        RulesPreset::Standard,
+        RulesPreset::Tournament,
+        RulesPreset::Arcade,
+    ];
+
+    pub fn rules(&self) -> CombatRules {
+        match self {
+            RulesPreset::Standard => CombatRules::STANDARD,
+            RulesPreset::Tournament => CombatRules::TOURNAMENT,
+            RulesPreset::Arcade => CombatRules::ARCADE,
+        }
+    }
+}
+
+impl std::fmt::Display for RulesPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesPreset::Standard => write!(f, "Standard"),
+            RulesPreset::Tournament => write!(f, "Tournament"),
+            RulesPreset::Arcade => write!(f, "Arcade"),
+        }
+    }
+}
+
+impl std::str::FromStr for RulesPreset {
+    type Err = UnknownRulesPreset;
+
+    /// Parse a preset by name, ignoring case.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        RulesPreset::ALL
+            .into_iter()
+            .find(|preset| preset.to_string().eq_ignore_ascii_case(name))
+            .ok_or_else(|| UnknownRulesPreset(name.to_string()))
+    }
+}
+
+/// A rules preset name that doesn't match any built-in ruleset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownRulesPreset(pub String);
+
+impl std::fmt::Display for UnknownRulesPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = RulesPreset::ALL.iter().map(|p| p.to_string()).collect();
+        write!(
+            f,
+            "unknown rules '{}', choose from: {}",
+            self.0,
+            names.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownRulesPreset {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_scaling_stays_within_bounds() {
+        let damage = CombatRules::STANDARD.combo_damage;
+        assert_eq!(damage.factor(0), 1.0);
+        assert!((damage.factor(2) - 0.7).abs() < 0.001);
+        assert_eq!(damage.factor(20), 0.4);
+
+        let knockback = CombatRules::STANDARD.combo_knockback;
+        assert!((knockback.factor(5) - 2.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn table_scaling_holds_its_last_entry() {
+        let table = Scaling::Table(&[1.0, 0.5, 0.25]);
+        assert_eq!(table.factor(1), 0.5);
+        assert_eq!(table.factor(10), 0.25);
+        assert_eq!(Scaling::NONE.factor(7), 1.0);
+    }
+
+    #[test]
+    fn presets_parse_by_name() {
+        for preset in RulesPreset::ALL {
+            assert_eq!(preset.to_string().to_lowercase().parse(), Ok(preset));
+        }
+        assert_eq!("TOURNAMENT".parse(), Ok(RulesPreset::Tournament));
+        assert!("casual".parse::<RulesPreset>().is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+
+const STALE_QUEUE_LEN: usize = 9; // landed hits remembered
+/// Damage taken off a move for each time it appears in the queue, by position
+/// (most recent hit first). A move filling the whole queue deals ~42% damage.
+const STALE_WEIGHTS: [f32; STALE_QUEUE_LEN] =
+    [0.08, 0.076, 0.072, 0.068, 0.064, 0.06, 0.056, 0.052, 0.048];
+
+/// The moves a fighter has recently landed, most recent first.
+///
+/// Each entry stales later uses of the same move, with recent entries weighing
+/// more than old ones. Only hits that land are recorded, so whiffs stay fresh.
+#[derive(Debug, Clone, Default)]
+pub struct StaleQueue {
+    recent: VecDeque<&'static str>,
+}
+
+impl StaleQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a landed hit with the move named `attack`, pushing out the oldest entry.
+    pub fn record(&mut self, attack: &'static str) {
+        self.recent.push_front(attack);
+        self.recent.truncate(STALE_QUEUE_LEN);
+    }
+
+    /// Damage multiplier for `attack` (1.0 = fresh).
+    pub fn multiplier(&self, attack: &str) -> f32 {
+        let stale: f32 = self
+            .recent
+            .iter()
+            .zip(STALE_WEIGHTS)
+            .filter(|(name, _)| **name == attack)
+            .map(|(_, weight)| weight)
+            .sum();
+        1.0 - stale
+    }
+
+    pub fn clear(&mut self) {
+        self.recent.clear();
+    }
+
+    /// Every move in the queue with its current multiplier, most recently landed first.
+    pub fn moves(&self) -> Vec<(&'static str, f32)> {
+        let mut moves: Vec<(&'static str, f32)> = Vec::new();
+        for &name in &self.recent {
+            if !moves.iter().any(|(seen, _)| *seen == name) {
+                moves.push((name, self.multiplier(name)));
+            }
+        }
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_moves_deal_full_damage() {
+        let mut queue = StaleQueue::new();
+        queue.record("Hook");
+        assert_eq!(queue.multiplier("Cross Punch"), 1.0);
+        assert!(queue.multiplier("Hook") < 1.0);
+    }
+
+    #[test]
+    fn alternating_moves_still_stale() {
+        let mut queue = StaleQueue::new();
+        for _ in 0..3 {
+            queue.record("Cross Punch");
+            queue.record("Hook");
+        }
+        assert!(queue.multiplier("Cross Punch") < 0.85);
+        assert!(queue.multiplier("Hook") < 0.85);
+    }
+
+    #[test]
+    fn recent_hits_weigh_more() {
+        let mut queue = StaleQueue::new();
+        queue.record("Hook");
+        queue.record("Cross Punch");
+        assert!(queue.multiplier("Cross Punch") < queue.multiplier("Hook"));
+    }
+
+    #[test]
+    fn old_hits_fall_out_of_the_queue() {
+        let mut queue = StaleQueue::new();
+        queue.record("Hook");
+        for _ in 0..STALE_QUEUE_LEN {
+            queue.record("Cross Punch");
+        }
+        assert_eq!(queue.multiplier("Hook"), 1.0);
+        assert!((queue.multiplier("Cross Punch") - 0.424).abs() < 0.001);
+        assert_eq!(queue.moves().len(), 1);
+    }
+}
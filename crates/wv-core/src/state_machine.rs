@@ -1,4 +1,4 @@
-/// The 9 possible states a fighter can be in.
+/// The possible states a fighter can be in.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FighterState {
     Idle,
@@ -9,7 +9,64 @@ pub enum FighterState {
     HitStun,
     Airborne,
     Knockdown,
+    /// Legs swept out: a quick fall onto the back, lying low on the ground.
+    Swept,
     GettingUp,
+    Falling,
+    TechRoll,
+    /// Moving into or out of a stance.
+    StanceChange,
+}
+
+const GETUP_FRAMES: u32 = 20;
+const QUICK_RISE_GETUP_FRAMES: u32 = 8;
+const DELAYED_WAKEUP_FRAMES: u32 = 30;
+const SWEPT_DOWN_FRAMES: u32 = 30; // on the ground after a sweep, before getting up
+const SWEPT_GETUP_FRAMES: u32 = 14; // rolling up off the back after a sweep
+const KIP_UP_FRAMES: u32 = 6; // a swept fighter's quick rise
+/// Minimum time on the ground before a quick rise or tech roll kicks in.
+pub const TECH_MIN_DOWN_FRAMES: u32 = 8;
+pub const TECH_ROLL_FRAMES: u32 = 24;
+/// Tech rolls are fully invincible for this many frames, then punishable.
+pub const TECH_ROLL_INVULN_FRAMES: u32 = 16;
+
+/// How a knockdown was caused. Hard knockdowns cannot be teched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnockdownKind {
+    Soft,
+    Hard,
+    /// Legs swept out from under the fighter.
+    Sweep,
+}
+
+impl std::fmt::Display for KnockdownKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KnockdownKind::Soft => write!(f, "Soft"),
+            KnockdownKind::Hard => write!(f, "Hard"),
+            KnockdownKind::Sweep => write!(f, "Sweep"),
+        }
+    }
+}
+
+/// Wake-up option chosen by the player while knocked down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WakeUp {
+    QuickRise,
+    TechRollForward,
+    TechRollBack,
+    Delayed,
+}
+
+/// Which way a dash goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DashKind {
+    /// Toward the opponent, invincible during the fighter's dash frames.
+    Forward,
+    /// Away from the opponent, invincible only for the fighter's backdash frames.
+    Back,
+    /// Along the z-axis, evading attacks during the fighter's sidestep frames.
+    Side,
 }
 
 /// Which attack is being performed (used when state == Attacking).
@@ -30,6 +87,10 @@ pub enum ActiveAttack {
     Aerial,
     ComboFinisher,
     Super,
+    /// Meter-powered version of the special attack.
+    EnhancedSpecial,
+    /// A direction + button variant, by index into the fighter's command normals.
+    Command(usize),
 }
 
 #[derive(Debug, Clone)]
@@ -42,7 +103,26 @@ pub struct StateMachine {
     pub attack_startup: u32,
     pub attack_active: u32,
     pub attack_recovery: u32,
-    pub hit_connected: bool, // prevents multi-hit per attack
+    pub hit_connected: bool, // the current attack has landed at least once
+    /// Hits landed by the current attack so far.
+    pub hits_landed: u32,
+    /// Frame of the current attack on which it last landed.
+    pub last_hit_frame: u32,
+    pub knockdown_kind: KnockdownKind,
+    /// Wake-up chosen during the current knockdown (kept for the duration of a tech roll).
+    pub wakeup: Option<WakeUp>,
+    /// Index of the stance the fighter is in (None = base stance).
+    pub stance: Option<usize>,
+    /// Stance being changed to during `StanceChange`.
+    pub stance_target: Option<usize>,
+    /// Direction of the current (or last) dash.
+    pub dash_kind: DashKind,
+}
+
+impl Default for StateMachine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl StateMachine {
@@ -57,6 +137,13 @@ impl StateMachine {
             attack_active: 0,
             attack_recovery: 0,
             hit_connected: false,
+            hits_landed: 0,
+            last_hit_frame: 0,
+            knockdown_kind: KnockdownKind::Soft,
+            wakeup: None,
+            stance: None,
+            stance_target: None,
+            dash_kind: DashKind::Forward,
         }
     }
 
@@ -72,16 +159,24 @@ impl StateMachine {
 
     /// Whether the fighter is in a state where they can be hit.
     pub fn is_vulnerable(&self) -> bool {
-        !matches!(
-            self.state,
-            FighterState::Dashing | FighterState::GettingUp | FighterState::Knockdown
-        )
+        match self.state {
+            FighterState::GettingUp
+            | FighterState::Knockdown
+            | FighterState::Swept
+            | FighterState::Falling => false,
+            FighterState::TechRoll => self.frame_counter >= TECH_ROLL_INVULN_FRAMES,
+            _ => true,
+        }
+    }
+
+    /// Whether the fighter is lying on the ground (knocked down or swept).
+    pub fn is_down(&self) -> bool {
+        matches!(self.state, FighterState::Knockdown | FighterState::Swept)
     }
 
     /// Whether the fighter is currently in the active frames of an attack.
     pub fn is_attack_active(&self) -> bool {
-        self.state == FighterState::Attacking
-            && self.attack_phase == Some(AttackPhase::Active)
+        self.state == FighterState::Attacking && self.attack_phase == Some(AttackPhase::Active)
     }
 
     /// Start an attack. Returns false if the fighter can't attack right now.
@@ -104,9 +199,56 @@ impl StateMachine {
         self.total_frames = startup + active + recovery;
         self.frame_counter = 0;
         self.hit_connected = false;
+        self.hits_landed = 0;
+        self.last_hit_frame = 0;
         true
     }
 
+    /// Abandon the current attack and return to neutral.
+    pub fn cancel_attack(&mut self) {
+        if self.state == FighterState::Attacking {
+            self.state = FighterState::Idle;
+            self.attack = None;
+            self.attack_phase = None;
+            self.frame_counter = 0;
+        }
+    }
+
+    /// Whether the current attack may land another hit, given its hit count and re-hit interval.
+    pub fn can_hit(&self, max_hits: u32, rehit_interval: u32) -> bool {
+        self.hits_landed < max_hits
+            && (self.hits_landed == 0 || self.frame_counter >= self.last_hit_frame + rehit_interval)
+    }
+
+    /// Record that the current attack just landed a hit.
+    pub fn register_hit(&mut self) {
+        self.hit_connected = true;
+        self.hits_landed += 1;
+        self.last_hit_frame = self.frame_counter;
+    }
+
+    /// Whether the current attack has connected and is recovering, so it can be cancelled.
+    pub fn can_cancel(&self) -> bool {
+        self.state == FighterState::Attacking
+            && self.attack_phase == Some(AttackPhase::Recovery)
+            && self.hit_connected
+    }
+
+    /// Cancel a connected attack's recovery straight into a new attack.
+    pub fn cancel_into_attack(
+        &mut self,
+        attack: ActiveAttack,
+        startup: u32,
+        active: u32,
+        recovery: u32,
+    ) -> bool {
+        if !self.can_cancel() {
+            return false;
+        }
+        self.state = FighterState::Idle;
+        self.start_attack(attack, startup, active, recovery)
+    }
+
     /// Start blocking.
     pub fn start_block(&mut self) -> bool {
         if !self.can_block() {
@@ -127,18 +269,38 @@ impl StateMachine {
     }
 
     /// Start a dash.
-    pub fn start_dash(&mut self, dash_frames: u32) -> bool {
+    pub fn start_dash(&mut self, kind: DashKind, dash_frames: u32) -> bool {
         if !self.can_act() {
             return false;
         }
         self.state = FighterState::Dashing;
+        self.dash_kind = kind;
         self.frame_counter = 0;
         self.total_frames = dash_frames;
         true
     }
 
+    /// Start moving into `target` (None = back to the base stance) over `frames`.
+    pub fn change_stance(&mut self, target: Option<usize>, frames: u32) -> bool {
+        if !self.can_act() || target == self.stance {
+            return false;
+        }
+        self.state = FighterState::StanceChange;
+        self.stance_target = target;
+        self.frame_counter = 0;
+        self.total_frames = frames;
+        true
+    }
+
+    /// Getting hit out of a stance drops the fighter back to the base stance.
+    fn break_stance(&mut self) {
+        self.stance = None;
+        self.stance_target = None;
+    }
+
     /// Enter hitstun (from being hit).
     pub fn enter_hitstun(&mut self, stun_frames: u32) {
+        self.break_stance();
         self.state = FighterState::HitStun;
         self.frame_counter = 0;
         self.total_frames = stun_frames;
@@ -148,6 +310,7 @@ impl StateMachine {
 
     /// Enter airborne state (launched).
     pub fn enter_airborne(&mut self) {
+        self.break_stance();
         self.state = FighterState::Airborne;
         self.frame_counter = 0;
         self.total_frames = 0; // ends when landing
@@ -156,10 +319,72 @@ impl StateMachine {
     }
 
     /// Enter knockdown (from landing while airborne or hard knockdown).
-    pub fn enter_knockdown(&mut self, down_frames: u32) {
+    pub fn enter_knockdown(&mut self, down_frames: u32, kind: KnockdownKind) {
+        self.break_stance();
         self.state = FighterState::Knockdown;
         self.frame_counter = 0;
         self.total_frames = down_frames;
+        self.knockdown_kind = kind;
+        self.wakeup = None;
+    }
+
+    /// Keep a downed fighter down `frames` longer, without changing how they went down.
+    pub fn extend_down(&mut self, frames: u32) {
+        if self.is_down() {
+            self.total_frames += frames;
+        }
+    }
+
+    /// Enter the swept state (legs taken out by a sweep).
+    pub fn enter_swept(&mut self) {
+        self.break_stance();
+        self.state = FighterState::Swept;
+        self.frame_counter = 0;
+        self.total_frames = SWEPT_DOWN_FRAMES;
+        self.knockdown_kind = KnockdownKind::Sweep;
+        self.wakeup = None;
+        self.attack = None;
+        self.attack_phase = None;
+    }
+
+    /// Pick a wake-up option while knocked down. A delayed wake-up can still be
+    /// swapped for a tech or quick rise; otherwise only the first choice counts.
+    /// Hard knockdowns only allow a delayed wake-up. Swept fighters are on their
+    /// back and can kip up (quick rise) or stay down, but not tech roll.
+    pub fn choose_wakeup(&mut self, choice: WakeUp) -> bool {
+        if !self.is_down() {
+            return false;
+        }
+        let delayed = match self.wakeup {
+            None => false,
+            Some(WakeUp::Delayed) if choice != WakeUp::Delayed => true,
+            Some(_) => return false,
+        };
+        if self.knockdown_kind == KnockdownKind::Hard && choice != WakeUp::Delayed {
+            return false;
+        }
+        if self.state == FighterState::Swept
+            && matches!(choice, WakeUp::TechRollForward | WakeUp::TechRollBack)
+        {
+            return false;
+        }
+        if choice == WakeUp::Delayed {
+            self.total_frames += DELAYED_WAKEUP_FRAMES;
+        } else if delayed {
+            self.total_frames -= DELAYED_WAKEUP_FRAMES;
+        }
+        self.wakeup = Some(choice);
+        true
+    }
+
+    /// Enter the falling state (knocked over an open stage edge). Never ends.
+    pub fn enter_falling(&mut self) {
+        self.break_stance();
+        self.state = FighterState::Falling;
+        self.frame_counter = 0;
+        self.total_frames = 0;
+        self.attack = None;
+        self.attack_phase = None;
     }
 
     /// Set moving state.
@@ -195,11 +420,10 @@ impl StateMachine {
                     }
                 } else if self.frame_counter
                     <= self.attack_startup + self.attack_active + self.attack_recovery
+                    && self.attack_phase != Some(AttackPhase::Recovery)
                 {
-                    if self.attack_phase != Some(AttackPhase::Recovery) {
-                        self.attack_phase = Some(AttackPhase::Recovery);
-                        return true;
-                    }
+                    self.attack_phase = Some(AttackPhase::Recovery);
+                    return true;
                 }
 
                 if self.frame_counter >= self.total_frames {
@@ -232,17 +456,64 @@ impl StateMachine {
                 false
             }
 
-            FighterState::Airborne => {
+            FighterState::Airborne | FighterState::Falling => {
                 self.frame_counter += 1;
                 false
             }
 
             FighterState::Knockdown => {
                 self.frame_counter += 1;
-                if self.frame_counter >= self.total_frames {
+                let teched = matches!(
+                    self.wakeup,
+                    Some(WakeUp::QuickRise | WakeUp::TechRollForward | WakeUp::TechRollBack)
+                ) && self.frame_counter >= TECH_MIN_DOWN_FRAMES;
+                if teched || self.frame_counter >= self.total_frames {
+                    self.frame_counter = 0;
+                    match self.wakeup {
+                        Some(WakeUp::TechRollForward | WakeUp::TechRollBack) => {
+                            self.state = FighterState::TechRoll;
+                            self.total_frames = TECH_ROLL_FRAMES;
+                        }
+                        Some(WakeUp::QuickRise) => {
+                            self.state = FighterState::GettingUp;
+                            self.total_frames = QUICK_RISE_GETUP_FRAMES;
+                            self.wakeup = None;
+                        }
+                        _ => {
+                            self.state = FighterState::GettingUp;
+                            self.total_frames = GETUP_FRAMES;
+                            self.wakeup = None;
+                        }
+                    }
+                    return true;
+                }
+                false
+            }
+
+            FighterState::Swept => {
+                self.frame_counter += 1;
+                let kipped_up = self.wakeup == Some(WakeUp::QuickRise)
+                    && self.frame_counter >= TECH_MIN_DOWN_FRAMES;
+                if kipped_up || self.frame_counter >= self.total_frames {
                     self.state = FighterState::GettingUp;
                     self.frame_counter = 0;
-                    self.total_frames = 20;
+                    self.total_frames = if kipped_up {
+                        KIP_UP_FRAMES
+                    } else {
+                        SWEPT_GETUP_FRAMES
+                    };
+                    self.wakeup = None;
+                    return true;
+                }
+                false
+            }
+
+            FighterState::TechRoll => {
+                self.frame_counter += 1;
+                if self.frame_counter >= self.total_frames {
+                    self.state = FighterState::Idle;
+                    self.frame_counter = 0;
+                    self.wakeup = None;
                     return true;
                 }
                 false
@@ -257,13 +528,24 @@ impl StateMachine {
                 }
                 false
             }
+
+            FighterState::StanceChange => {
+                self.frame_counter += 1;
+                if self.frame_counter >= self.total_frames {
+                    self.state = FighterState::Idle;
+                    self.frame_counter = 0;
+                    self.stance = self.stance_target.take();
+                    return true;
+                }
+                false
+            }
         }
     }
 
     /// Called when physics detects a landing while airborne.
     pub fn land(&mut self) {
         if self.state == FighterState::Airborne {
-            self.enter_knockdown(30);
+            self.enter_knockdown(30, KnockdownKind::Soft);
         }
     }
 }
@@ -279,7 +561,11 @@ impl std::fmt::Display for FighterState {
             FighterState::HitStun => write!(f, "HitStun"),
             FighterState::Airborne => write!(f, "Airborne"),
             FighterState::Knockdown => write!(f, "Knockdown"),
+            FighterState::Swept => write!(f, "Swept"),
             FighterState::GettingUp => write!(f, "Getting Up"),
+            FighterState::Falling => write!(f, "Falling"),
+            FighterState::TechRoll => write!(f, "Tech Roll"),
+            FighterState::StanceChange => write!(f, "Stance Change"),
         }
     }
 }
@@ -328,7 +614,7 @@ mod tests {
     #[test]
     fn dash_transitions() {
         let mut sm = StateMachine::new();
-        assert!(sm.start_dash(10));
+        assert!(sm.start_dash(DashKind::Forward, 10));
         assert_eq!(sm.state, FighterState::Dashing);
 
         for _ in 0..10 {
@@ -352,7 +638,7 @@ mod tests {
     #[test]
     fn knockdown_to_getup_to_idle() {
         let mut sm = StateMachine::new();
-        sm.enter_knockdown(30);
+        sm.enter_knockdown(30, KnockdownKind::Soft);
         assert_eq!(sm.state, FighterState::Knockdown);
 
         for _ in 0..30 {
@@ -366,6 +652,112 @@ mod tests {
         assert_eq!(sm.state, FighterState::Idle);
     }
 
+    #[test]
+    fn quick_rise_shortens_knockdown() {
+        let mut sm = StateMachine::new();
+        sm.enter_knockdown(30, KnockdownKind::Soft);
+        assert!(sm.choose_wakeup(WakeUp::QuickRise));
+
+        for _ in 0..TECH_MIN_DOWN_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::GettingUp);
+        for _ in 0..QUICK_RISE_GETUP_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::Idle);
+    }
+
+    #[test]
+    fn tech_roll_is_invincible_then_punishable() {
+        let mut sm = StateMachine::new();
+        sm.enter_knockdown(30, KnockdownKind::Soft);
+        assert!(sm.choose_wakeup(WakeUp::TechRollBack));
+        // Only the first choice counts
+        assert!(!sm.choose_wakeup(WakeUp::QuickRise));
+
+        for _ in 0..TECH_MIN_DOWN_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::TechRoll);
+        assert_eq!(sm.wakeup, Some(WakeUp::TechRollBack));
+        assert!(!sm.is_vulnerable());
+
+        for _ in 0..TECH_ROLL_INVULN_FRAMES {
+            sm.tick();
+        }
+        assert!(sm.is_vulnerable());
+
+        for _ in TECH_ROLL_INVULN_FRAMES..TECH_ROLL_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::Idle);
+        assert_eq!(sm.wakeup, None);
+    }
+
+    #[test]
+    fn hard_knockdown_is_untechable() {
+        let mut sm = StateMachine::new();
+        sm.enter_knockdown(40, KnockdownKind::Hard);
+        assert!(!sm.choose_wakeup(WakeUp::QuickRise));
+        assert!(!sm.choose_wakeup(WakeUp::TechRollForward));
+        // Staying down is always allowed
+        assert!(sm.choose_wakeup(WakeUp::Delayed));
+    }
+
+    #[test]
+    fn swept_fighters_kip_up_but_cannot_tech_roll() {
+        let mut sm = StateMachine::new();
+        sm.enter_swept();
+        assert!(!sm.is_vulnerable());
+        assert!(!sm.choose_wakeup(WakeUp::TechRollForward));
+        assert!(sm.choose_wakeup(WakeUp::QuickRise));
+        for _ in 0..TECH_MIN_DOWN_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::GettingUp);
+        assert_eq!(sm.total_frames, KIP_UP_FRAMES);
+
+        let mut sm = StateMachine::new();
+        sm.enter_swept();
+        for _ in 0..SWEPT_DOWN_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::GettingUp);
+        assert_eq!(sm.total_frames, SWEPT_GETUP_FRAMES);
+    }
+
+    #[test]
+    fn tech_overrides_a_delayed_wakeup() {
+        let mut sm = StateMachine::new();
+        sm.enter_knockdown(30, KnockdownKind::Soft);
+        assert!(sm.choose_wakeup(WakeUp::Delayed));
+        assert!(!sm.choose_wakeup(WakeUp::Delayed));
+        assert!(sm.choose_wakeup(WakeUp::TechRollForward));
+        assert_eq!(sm.total_frames, 30);
+        // A tech is final
+        assert!(!sm.choose_wakeup(WakeUp::Delayed));
+        for _ in 0..TECH_MIN_DOWN_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::TechRoll);
+    }
+
+    #[test]
+    fn delayed_wakeup_stays_down_longer() {
+        let mut sm = StateMachine::new();
+        sm.enter_knockdown(30, KnockdownKind::Soft);
+        assert!(sm.choose_wakeup(WakeUp::Delayed));
+        for _ in 0..30 {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::Knockdown);
+        for _ in 0..DELAYED_WAKEUP_FRAMES {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::GettingUp);
+    }
+
     #[test]
     fn airborne_land() {
         let mut sm = StateMachine::new();
@@ -376,11 +768,96 @@ mod tests {
         assert_eq!(sm.state, FighterState::Knockdown);
     }
 
+    #[test]
+    fn falling_is_terminal() {
+        let mut sm = StateMachine::new();
+        sm.start_attack(ActiveAttack::Light, 4, 3, 6);
+        sm.enter_falling();
+        assert_eq!(sm.state, FighterState::Falling);
+        assert_eq!(sm.attack, None);
+
+        for _ in 0..100 {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::Falling);
+        assert!(!sm.can_act());
+        assert!(!sm.is_vulnerable());
+    }
+
+    #[test]
+    fn cancel_requires_connected_recovery() {
+        let mut sm = StateMachine::new();
+        sm.start_attack(ActiveAttack::Light, 2, 2, 10);
+        for _ in 0..5 {
+            sm.tick();
+        }
+        assert_eq!(sm.attack_phase, Some(AttackPhase::Recovery));
+        assert!(!sm.cancel_into_attack(ActiveAttack::Special, 4, 3, 6));
+
+        sm.hit_connected = true;
+        assert!(sm.cancel_into_attack(ActiveAttack::Special, 4, 3, 6));
+        assert_eq!(sm.attack, Some(ActiveAttack::Special));
+        assert_eq!(sm.attack_phase, Some(AttackPhase::Startup));
+    }
+
+    #[test]
+    fn multi_hit_respects_rehit_interval() {
+        let mut sm = StateMachine::new();
+        sm.start_attack(ActiveAttack::ComboFinisher, 1, 12, 5);
+        sm.tick();
+        sm.tick();
+        assert!(sm.can_hit(2, 6));
+        sm.register_hit();
+        for _ in 0..5 {
+            sm.tick();
+            assert!(!sm.can_hit(2, 6));
+        }
+        sm.tick();
+        assert!(sm.can_hit(2, 6));
+        sm.register_hit();
+        assert_eq!(sm.hits_landed, 2);
+        sm.tick();
+        assert!(!sm.can_hit(2, 6));
+    }
+
     #[test]
     fn cannot_act_during_attack() {
         let mut sm = StateMachine::new();
         sm.start_attack(ActiveAttack::Light, 4, 3, 6);
         assert!(!sm.start_attack(ActiveAttack::Heavy, 10, 4, 12));
-        assert!(!sm.start_dash(10));
+        assert!(!sm.start_dash(DashKind::Forward, 10));
+    }
+
+    #[test]
+    fn stance_change_completes_after_transition() {
+        let mut sm = StateMachine::new();
+        assert!(sm.change_stance(Some(0), 3));
+        assert!(!sm.can_act());
+        for _ in 0..3 {
+            sm.tick();
+        }
+        assert_eq!(sm.state, FighterState::Idle);
+        assert_eq!(sm.stance, Some(0));
+        assert!(!sm.change_stance(Some(0), 3));
+    }
+
+    #[test]
+    fn getting_hit_breaks_stance() {
+        let mut sm = StateMachine::new();
+        sm.change_stance(Some(0), 1);
+        sm.tick();
+        sm.enter_hitstun(10);
+        assert_eq!(sm.stance, None);
+    }
+
+    #[test]
+    fn dash_invincibility_comes_from_frame_data() {
+        // The fighter's dash data decides which frames are invincible
+        let mut sm = StateMachine::new();
+        sm.start_dash(DashKind::Forward, 10);
+        assert!(sm.is_vulnerable());
+        let mut sm = StateMachine::new();
+        sm.start_dash(DashKind::Back, 10);
+        assert!(sm.is_vulnerable());
     }
 }
@@ -0,0 +1,183 @@
+/// Bounds for effective stats after modifiers are applied.
+const MIN_STAT: f32 = 0.25;
+const MAX_STAT: f32 = 3.0;
+
+/// A stat that buffs and debuffs can change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stat {
+    Attack,
+    Defense,
+    Speed,
+    KnockbackResistance,
+}
+
+impl std::fmt::Display for Stat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stat::Attack => write!(f, "Attack"),
+            Stat::Defense => write!(f, "Defense"),
+            Stat::Speed => write!(f, "Speed"),
+            Stat::KnockbackResistance => write!(f, "Knockback Resistance"),
+        }
+    }
+}
+
+/// Core combat stats. Higher is always better for the fighter who owns them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatBlock {
+    pub attack: f32,               // multiplier on damage dealt
+    pub defense: f32,              // damage taken is divided by this
+    pub speed: f32,                // multiplier on move speed, divides attack startup
+    pub knockback_resistance: f32, // fraction of incoming knockback ignored (0..1)
+}
+
+impl StatBlock {
+    /// Neutral stats: no bonuses or penalties.
+    pub const BASE: StatBlock = StatBlock {
+        attack: 1.0,
+        defense: 1.0,
+        speed: 1.0,
+        knockback_resistance: 0.0,
+    };
+
+    pub fn get(&self, stat: Stat) -> f32 {
+        match stat {
+            Stat::Attack => self.attack,
+            Stat::Defense => self.defense,
+            Stat::Speed => self.speed,
+            Stat::KnockbackResistance => self.knockback_resistance,
+        }
+    }
+
+    fn get_mut(&mut self, stat: Stat) -> &mut f32 {
+        match stat {
+            Stat::Attack => &mut self.attack,
+            Stat::Defense => &mut self.defense,
+            Stat::Speed => &mut self.speed,
+            Stat::KnockbackResistance => &mut self.knockback_resistance,
+        }
+    }
+}
+
+/// A timed buff (positive amount) or debuff (negative amount) on one stat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatModifier {
+    pub stat: Stat,
+    pub amount: f32, // added to the base stat while active
+    pub frames: u32, // remaining duration
+    pub source: &'static str,
+}
+
+/// The timed modifiers currently affecting a fighter.
+///
+/// Stacking rules:
+/// - a modifier from the same source on the same stat refreshes the existing one
+///   (the new amount and duration replace the old) instead of stacking;
+/// - modifiers from different sources add together;
+/// - the resulting stat is clamped, so stacking can never zero out or run away.
+#[derive(Debug, Clone, Default)]
+pub struct StatModifiers {
+    active: Vec<StatModifier>,
+}
+
+impl StatModifiers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a modifier following the stacking rules.
+    pub fn apply(&mut self, modifier: StatModifier) {
+        match self
+            .active
+            .iter_mut()
+            .find(|m| m.stat == modifier.stat && m.source == modifier.source)
+        {
+            Some(existing) => *existing = modifier,
+            None => self.active.push(modifier),
+        }
+    }
+
+    /// Count down durations and drop expired modifiers.
+    pub fn tick(&mut self) {
+        for m in &mut self.active {
+            m.frames = m.frames.saturating_sub(1);
+        }
+        self.active.retain(|m| m.frames > 0);
+    }
+
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatModifier> {
+        self.active.iter()
+    }
+
+    /// Base stats with every active modifier applied and clamped.
+    pub fn effective(&self, base: &StatBlock) -> StatBlock {
+        let mut stats = *base;
+        for m in &self.active {
+            *stats.get_mut(m.stat) += m.amount;
+        }
+        for stat in [Stat::Attack, Stat::Defense, Stat::Speed] {
+            let value = stats.get_mut(stat);
+            *value = value.clamp(MIN_STAT, MAX_STAT);
+        }
+        stats.knockback_resistance = stats.knockback_resistance.clamp(0.0, 1.0);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modifier(stat: Stat, amount: f32, frames: u32, source: &'static str) -> StatModifier {
+        StatModifier {
+            stat,
+            amount,
+            frames,
+            source,
+        }
+    }
+
+    #[test]
+    fn modifiers_from_different_sources_stack() {
+        let mut mods = StatModifiers::new();
+        mods.apply(modifier(Stat::Attack, 0.2, 60, "a"));
+        mods.apply(modifier(Stat::Attack, 0.3, 60, "b"));
+        let stats = mods.effective(&StatBlock::BASE);
+        assert!((stats.attack - 1.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn same_source_refreshes() {
+        let mut mods = StatModifiers::new();
+        mods.apply(modifier(Stat::Defense, -0.2, 10, "a"));
+        mods.apply(modifier(Stat::Defense, -0.2, 60, "a"));
+        assert_eq!(mods.iter().count(), 1);
+        let stats = mods.effective(&StatBlock::BASE);
+        assert!((stats.defense - 0.8).abs() < 0.001);
+        assert_eq!(mods.iter().next().unwrap().frames, 60);
+    }
+
+    #[test]
+    fn modifiers_expire() {
+        let mut mods = StatModifiers::new();
+        mods.apply(modifier(Stat::Speed, 0.5, 3, "a"));
+        for _ in 0..3 {
+            mods.tick();
+        }
+        assert_eq!(mods.effective(&StatBlock::BASE), StatBlock::BASE);
+    }
+
+    #[test]
+    fn effective_stats_are_clamped() {
+        let mut mods = StatModifiers::new();
+        mods.apply(modifier(Stat::Speed, -5.0, 60, "a"));
+        mods.apply(modifier(Stat::KnockbackResistance, 2.0, 60, "a"));
+        let stats = mods.effective(&StatBlock::BASE);
+        assert_eq!(stats.speed, MIN_STAT);
+        assert_eq!(stats.knockback_resistance, 1.0);
+    }
+}
@@ -0,0 +1,164 @@
+const BURN_TICK_FRAMES: u32 = 20; // burn deals its damage every this many frames
+const MAX_BURN_STACKS: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Damage over time. Stacks up to `MAX_BURN_STACKS`; rolling puts it out.
+    Burn,
+    /// Guard is broken open: blocking is dropped and can't be restarted.
+    Stagger,
+    /// Can't act at all.
+    Stun,
+}
+
+impl std::fmt::Display for StatusKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusKind::Burn => write!(f, "Burn"),
+            StatusKind::Stagger => write!(f, "Stagger"),
+            StatusKind::Stun => write!(f, "Stun"),
+        }
+    }
+}
+
+/// A status effect an attack can inflict.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub frames: u32,  // duration
+    pub potency: f32, // burn: damage per tick per stack
+}
+
+impl StatusEffect {
+    /// Whether the effect still lands when the hit is blocked.
+    pub fn applies_through_block(&self) -> bool {
+        self.kind == StatusKind::Stagger
+    }
+}
+
+/// A status effect currently affecting a fighter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveStatus {
+    pub effect: StatusEffect,
+    pub remaining: u32,
+    pub stacks: u32,
+    elapsed: u32,
+}
+
+/// The status effects on a fighter.
+///
+/// Stacking rules:
+/// - Burn adds a stack (up to `MAX_BURN_STACKS`) and refreshes the duration;
+/// - Stagger and Stun refresh to the longer duration, never stacking.
+#[derive(Debug, Clone, Default)]
+pub struct StatusEffects {
+    active: Vec<ActiveStatus>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply an effect following the stacking rules.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        let Some(existing) = self
+            .active
+            .iter_mut()
+            .find(|s| s.effect.kind == effect.kind)
+        else {
+            self.active.push(ActiveStatus {
+                effect,
+                remaining: effect.frames,
+                stacks: 1,
+                elapsed: 0,
+            });
+            return;
+        };
+        match effect.kind {
+            StatusKind::Burn => {
+                existing.stacks = (existing.stacks + 1).min(MAX_BURN_STACKS);
+                existing.effect.potency = existing.effect.potency.max(effect.potency);
+                existing.remaining = effect.frames;
+            }
+            StatusKind::Stagger | StatusKind::Stun => {
+                existing.remaining = existing.remaining.max(effect.frames);
+            }
+        }
+    }
+
+    /// Advance one frame. Returns the burn damage dealt this frame.
+    pub fn tick(&mut self) -> f32 {
+        let mut damage = 0.0;
+        for status in &mut self.active {
+            status.elapsed += 1;
+            status.remaining = status.remaining.saturating_sub(1);
+            if status.effect.kind == StatusKind::Burn && status.elapsed % BURN_TICK_FRAMES == 0 {
+                damage += status.effect.potency * status.stacks as f32;
+            }
+        }
+        self.active.retain(|s| s.remaining > 0);
+        damage
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.active.iter().any(|s| s.effect.kind == kind)
+    }
+
+    /// Remove one kind of effect.
+    pub fn cleanse(&mut self, kind: StatusKind) {
+        self.active.retain(|s| s.effect.kind != kind);
+    }
+
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveStatus> {
+        self.active.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effect(kind: StatusKind, frames: u32, potency: f32) -> StatusEffect {
+        StatusEffect {
+            kind,
+            frames,
+            potency,
+        }
+    }
+
+    #[test]
+    fn burn_deals_damage_per_stack() {
+        let mut status = StatusEffects::new();
+        status.apply(effect(StatusKind::Burn, 120, 1.0));
+        status.apply(effect(StatusKind::Burn, 120, 1.0));
+        let damage: f32 = (0..BURN_TICK_FRAMES).map(|_| status.tick()).sum();
+        assert!((damage - 2.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn burn_stacks_are_capped() {
+        let mut status = StatusEffects::new();
+        for _ in 0..10 {
+            status.apply(effect(StatusKind::Burn, 120, 1.0));
+        }
+        assert_eq!(status.iter().next().unwrap().stacks, MAX_BURN_STACKS);
+    }
+
+    #[test]
+    fn effects_expire_and_cleanse() {
+        let mut status = StatusEffects::new();
+        status.apply(effect(StatusKind::Stun, 2, 0.0));
+        status.apply(effect(StatusKind::Burn, 60, 1.0));
+        status.tick();
+        status.tick();
+        assert!(!status.has(StatusKind::Stun));
+        assert!(status.has(StatusKind::Burn));
+        status.cleanse(StatusKind::Burn);
+        assert!(!status.has(StatusKind::Burn));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::physics::PhysicsBody;
 use crate::types::Vec3;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -25,13 +26,32 @@ impl std::fmt::Display for WeaponType {
     }
 }
 
+/// Kind of damage a hit deals, matched against the defender's resistances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageType {
+    Blunt,
+    Slash,
+    Arcane,
+}
+
+impl std::fmt::Display for DamageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DamageType::Blunt => write!(f, "blunt"),
+            DamageType::Slash => write!(f, "slash"),
+            DamageType::Arcane => write!(f, "arcane"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WeaponData {
     pub weapon_type: WeaponType,
     pub base_damage: f32,
-    pub attack_speed: f32,   // multiplier (1.0 = normal)
-    pub range: f32,          // hitbox reach in front of fighter
-    pub weight: f32,         // affects knockback dealt
+    pub attack_speed: f32,       // multiplier (1.0 = normal)
+    pub range: f32,              // hitbox reach in front of fighter
+    pub weight: f32,             // affects knockback dealt
+    pub damage_type: DamageType, // default for attacks that don't set their own
     pub hitbox_half_extents: Vec3,
 }
 
@@ -45,6 +65,27 @@ impl WeaponData {
     }
 }
 
+/// A weapon lying on the stage after being knocked out of a fighter's hands.
+/// Either fighter can pick it up while unarmed.
+#[derive(Debug, Clone)]
+pub struct WeaponItem {
+    pub weapon_type: WeaponType,
+    pub physics: PhysicsBody,
+}
+
+impl WeaponItem {
+    /// Spawn a dropped weapon at `position`, flung with `impulse`.
+    pub fn dropped(weapon_type: WeaponType, position: Vec3, impulse: Vec3) -> Self {
+        let mut physics = PhysicsBody::new(position);
+        physics.grounded = false;
+        physics.apply_knockback(impulse);
+        Self {
+            weapon_type,
+            physics,
+        }
+    }
+}
+
 // Unarmed: fast attacks, short range, low base damage
 // Damage tuned so fights last 15-25 hits (MK-style pacing)
 static UNARMED: WeaponData = WeaponData {
@@ -53,6 +94,7 @@ static UNARMED: WeaponData = WeaponData {
     attack_speed: 1.4,
     range: 1.0,
     weight: 0.6,
+    damage_type: DamageType::Blunt,
     hitbox_half_extents: Vec3::new(0.5, 0.4, 0.3),
 };
 
@@ -63,6 +105,7 @@ static SWORD_AND_SHIELD: WeaponData = WeaponData {
     attack_speed: 0.9,
     range: 1.8,
     weight: 2.0,
+    damage_type: DamageType::Slash,
     hitbox_half_extents: Vec3::new(0.9, 0.6, 0.4),
 };
 
@@ -73,5 +116,6 @@ static MAGIC: WeaponData = WeaponData {
     attack_speed: 1.1,
     range: 2.2,
     weight: 1.0,
+    damage_type: DamageType::Arcane,
     hitbox_half_extents: Vec3::new(0.8, 0.5, 0.4),
 };
@@ -1,11 +1,14 @@
 mod snapshot;
 
+use serde::Deserialize;
 use snapshot::{ArenaBounds, GameSnapshot};
 use wasm_bindgen::prelude::*;
+use wv_core::config::{DepthMode, Handicap, MatchConfig};
 use wv_core::fighter::{FighterData, FighterId};
 use wv_core::game::GameState;
 use wv_core::input::InputState;
-use wv_core::physics;
+use wv_core::physics::{self, StageEdge};
+use wv_core::rules::{RulesPreset, UnknownRulesPreset};
 
 fn parse_fighter_id(name: &str) -> Result<FighterId, JsError> {
     match name.to_lowercase().as_str() {
@@ -21,6 +24,98 @@ fn parse_fighter_id(name: &str) -> Result<FighterId, JsError> {
     }
 }
 
+fn parse_rules_preset(name: &str) -> Result<RulesPreset, JsError> {
+    name.parse()
+        .map_err(|err: UnknownRulesPreset| JsError::new(&err.to_string()))
+}
+
+/// Match settings from JS. Anything left out keeps the standard value.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct MatchSettings {
+    rounds: Option<u32>,
+    /// Seconds per round; `Infinity` for no time limit.
+    round_time: Option<f64>,
+    countdown_frames: Option<u32>,
+    round_over_frames: Option<u32>,
+    start_health: Option<f32>,
+    start_stamina: Option<f32>,
+    p1_handicap: Option<HandicapSettings>,
+    p2_handicap: Option<HandicapSettings>,
+    rules: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct HandicapSettings {
+    health: f32,
+    damage: f32,
+}
+
+impl Default for HandicapSettings {
+    fn default() -> Self {
+        HandicapSettings {
+            health: Handicap::NONE.health,
+            damage: Handicap::NONE.damage,
+        }
+    }
+}
+
+fn parse_match_config(settings: JsValue) -> Result<MatchConfig, JsError> {
+    let settings: MatchSettings = serde_wasm_bindgen::from_value(settings)
+        .map_err(|err| JsError::new(&format!("Invalid match settings: {}", err)))?;
+    let mut config = MatchConfig::default();
+    if let Some(rounds) = settings.rounds {
+        config.rounds = rounds;
+    }
+    if let Some(seconds) = settings.round_time {
+        config.round_time = if seconds == f64::INFINITY {
+            None
+        } else if seconds.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&seconds) {
+            Some(seconds as u32)
+        } else {
+            return Err(JsError::new(&format!(
+                "Invalid match settings: round time must be a whole number of seconds or Infinity, got {}",
+                seconds
+            )));
+        };
+    }
+    if let Some(frames) = settings.countdown_frames {
+        config.countdown_frames = frames;
+    }
+    if let Some(frames) = settings.round_over_frames {
+        config.round_over_frames = frames;
+    }
+    if let Some(pct) = settings.start_health {
+        config.start_health = pct;
+    }
+    if let Some(pct) = settings.start_stamina {
+        config.start_stamina = pct;
+    }
+    for (handicap, input) in config
+        .handicaps
+        .iter_mut()
+        .zip([settings.p1_handicap, settings.p2_handicap])
+    {
+        if let Some(input) = input {
+            *handicap = Handicap {
+                health: input.health,
+                damage: input.damage,
+            };
+        }
+    }
+    if let Some(rules) = settings.rules {
+        config.rules = parse_rules_preset(&rules)?.rules();
+    }
+    Ok(config)
+}
+
+fn new_game(p1: FighterId, p2: FighterId, config: MatchConfig) -> Result<WasmGame, JsError> {
+    let state = GameState::new(p1, p2, config)
+        .map_err(|err| JsError::new(&format!("Invalid match settings: {}", err)))?;
+    Ok(WasmGame { state })
+}
+
 fn to_js(snap: &GameSnapshot) -> JsValue {
     serde_wasm_bindgen::to_value(snap).unwrap_or(JsValue::NULL)
 }
@@ -37,26 +132,54 @@ impl WasmGame {
     pub fn new(p1: &str, p2: &str) -> Result<WasmGame, JsError> {
         let p1_id = parse_fighter_id(p1)?;
         let p2_id = parse_fighter_id(p2)?;
-        Ok(WasmGame {
-            state: GameState::new(p1_id, p2_id),
-        })
+        new_game(p1_id, p2_id, MatchConfig::default())
+    }
+
+    /// Create a new game with custom match settings, e.g.
+    /// `{ rounds: 5, round_time: Infinity, start_health: 0.5, p2_handicap: { damage: 0.8 }, rules: "Tournament" }`.
+    /// Fractions are 0-1. Throws if the settings are invalid.
+    pub fn with_config(p1: &str, p2: &str, settings: JsValue) -> Result<WasmGame, JsError> {
+        let p1_id = parse_fighter_id(p1)?;
+        let p2_id = parse_fighter_id(p2)?;
+        new_game(p1_id, p2_id, parse_match_config(settings)?)
     }
 
     /// Advance one frame with explicit boolean inputs.
     ///
     /// P1: move_fwd, move_back, move_left, move_right, light, heavy, special, block, dash, mid_kick, low_kick, aerial
     /// P2: same order
+    /// Then pickup for P1 and P2, then stance for P1 and P2.
     #[allow(clippy::too_many_arguments)]
     pub fn tick(
         &mut self,
-        p1_fwd: bool, p1_back: bool, p1_left: bool, p1_right: bool,
-        p1_light: bool, p1_heavy: bool, p1_special: bool,
-        p1_block: bool, p1_dash: bool,
-        p1_mid_kick: bool, p1_low_kick: bool, p1_aerial: bool,
-        p2_fwd: bool, p2_back: bool, p2_left: bool, p2_right: bool,
-        p2_light: bool, p2_heavy: bool, p2_special: bool,
-        p2_block: bool, p2_dash: bool,
-        p2_mid_kick: bool, p2_low_kick: bool, p2_aerial: bool,
+        p1_fwd: bool,
+        p1_back: bool,
+        p1_left: bool,
+        p1_right: bool,
+        p1_light: bool,
+        p1_heavy: bool,
+        p1_special: bool,
+        p1_block: bool,
+        p1_dash: bool,
+        p1_mid_kick: bool,
+        p1_low_kick: bool,
+        p1_aerial: bool,
+        p2_fwd: bool,
+        p2_back: bool,
+        p2_left: bool,
+        p2_right: bool,
+        p2_light: bool,
+        p2_heavy: bool,
+        p2_special: bool,
+        p2_block: bool,
+        p2_dash: bool,
+        p2_mid_kick: bool,
+        p2_low_kick: bool,
+        p2_aerial: bool,
+        p1_pickup: bool,
+        p2_pickup: bool,
+        p1_stance: bool,
+        p2_stance: bool,
     ) -> JsValue {
         let p1_input = InputState {
             move_forward: p1_fwd,
@@ -71,6 +194,8 @@ impl WasmGame {
             mid_kick: p1_mid_kick,
             low_kick: p1_low_kick,
             aerial: p1_aerial,
+            pickup: p1_pickup,
+            stance: p1_stance,
         };
         let p2_input = InputState {
             move_forward: p2_fwd,
@@ -85,6 +210,8 @@ impl WasmGame {
             mid_kick: p2_mid_kick,
             low_kick: p2_low_kick,
             aerial: p2_aerial,
+            pickup: p2_pickup,
+            stance: p2_stance,
         };
         self.state.tick(&p1_input, &p2_input);
         to_js(&snapshot::snapshot(&self.state))
@@ -94,6 +221,8 @@ impl WasmGame {
     ///
     /// Bits 0-11 = P1 (fwd, back, left, right, light, heavy, special, block, dash, mid_kick, low_kick, aerial)
     /// Bits 12-23 = P2 (same order)
+    /// Bit 24 = P1 pickup, bit 25 = P2 pickup
+    /// Bit 26 = P1 stance, bit 27 = P2 stance
     pub fn tick_packed(&mut self, input: u32) -> JsValue {
         let p1_input = InputState {
             move_forward: input & (1 << 0) != 0,
@@ -108,6 +237,8 @@ impl WasmGame {
             mid_kick: input & (1 << 9) != 0,
             low_kick: input & (1 << 10) != 0,
             aerial: input & (1 << 11) != 0,
+            pickup: input & (1 << 24) != 0,
+            stance: input & (1 << 26) != 0,
         };
         let p2_input = InputState {
             move_forward: input & (1 << 12) != 0,
@@ -122,11 +253,39 @@ impl WasmGame {
             mid_kick: input & (1 << 21) != 0,
             low_kick: input & (1 << 22) != 0,
             aerial: input & (1 << 23) != 0,
+            pickup: input & (1 << 25) != 0,
+            stance: input & (1 << 27) != 0,
         };
         self.state.tick(&p1_input, &p2_input);
         to_js(&snapshot::snapshot(&self.state))
     }
 
+    /// Toggle the ring-out rule: open stages have no walls at the x edges.
+    pub fn set_open_stage(&mut self, open: bool) {
+        let edge = if open {
+            StageEdge::Open
+        } else {
+            StageEdge::Walled
+        };
+        self.state.set_stage_edge(edge);
+    }
+
+    /// Toggle free depth: fighters keep their own Z instead of being pulled onto
+    /// one line, so sidesteps only get caught by tracking attacks.
+    pub fn set_free_depth(&mut self, free: bool) {
+        self.state.config.depth_mode = if free {
+            DepthMode::Free
+        } else {
+            DepthMode::Aligned
+        };
+    }
+
+    /// Switch to a built-in combat ruleset (combo scaling, blocking, hitstun) by name.
+    pub fn set_rules(&mut self, preset: &str) -> Result<(), JsError> {
+        self.state.config.rules = parse_rules_preset(preset)?.rules();
+        Ok(())
+    }
+
     /// Get the current game snapshot without advancing a frame.
     pub fn get_snapshot(&self) -> JsValue {
         to_js(&snapshot::snapshot(&self.state))
@@ -147,13 +306,16 @@ impl WasmGame {
 /// Returns the list of available fighter names.
 #[wasm_bindgen]
 pub fn available_fighters() -> JsValue {
-    let names: Vec<&str> = FighterId::ALL.iter().map(|id| match id {
-        FighterId::Kael => "Kael",
-        FighterId::Knight => "Knight",
-        FighterId::Zara => "Zara",
-        FighterId::Magnus => "Magnus",
-        FighterId::Orin => "Orin",
-    }).collect();
+    let names: Vec<&str> = FighterId::ALL
+        .iter()
+        .map(|id| match id {
+            FighterId::Kael => "Kael",
+            FighterId::Knight => "Knight",
+            FighterId::Zara => "Zara",
+            FighterId::Magnus => "Magnus",
+            FighterId::Orin => "Orin",
+        })
+        .collect();
     serde_wasm_bindgen::to_value(&names).unwrap_or(JsValue::NULL)
 }
 
@@ -162,7 +324,7 @@ pub fn available_fighters() -> JsValue {
 pub fn fighter_animations(name: &str) -> Result<JsValue, JsError> {
     let id = parse_fighter_id(name)?;
     let data = FighterData::get(id);
-    let anims = snapshot::animation_set_snapshot(&data.animations, &data.moveset);
+    let anims = snapshot::animation_set_snapshot(&data.animations, &data.moveset, data.stances);
     serde_wasm_bindgen::to_value(&anims).map_err(|e| JsError::new(&e.to_string()))
 }
 
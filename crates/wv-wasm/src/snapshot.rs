@@ -1,6 +1,6 @@
 use serde::Serialize;
-use wv_core::fighter::{AnimationSet, MoveSet};
-use wv_core::game::{GamePhase, GameState};
+use wv_core::fighter::{AnimationSet, AttackClass, MoveSet, Stance};
+use wv_core::game::{GamePhase, GameState, RoundEndReason, RoundResult, METER_BARS, METER_PER_BAR};
 use wv_core::state_machine::{ActiveAttack, AttackPhase, FighterState};
 
 #[derive(Serialize)]
@@ -16,6 +16,10 @@ pub struct AttackSnapshot {
     pub phase: &'static str,
     pub phase_num: u8,
     pub hit_connected: bool,
+    /// Hits landed so far (1 = first hit); play per-hit effects when this increases.
+    pub hit_index: u32,
+    /// How many times this attack can hit.
+    pub hit_count: u32,
     pub frame_counter: u32,
     pub total_frames: u32,
 }
@@ -24,6 +28,7 @@ pub struct AttackSnapshot {
 pub struct FighterSnapshot {
     pub fighter_id: String,
     pub weapon_type: String,
+    pub disarmed: bool,
     pub position: Vec3Snapshot,
     pub velocity: Vec3Snapshot,
     pub health: f32,
@@ -32,16 +37,96 @@ pub struct FighterSnapshot {
     pub stamina: f32,
     pub max_stamina: f32,
     pub stamina_pct: f32,
+    pub exhausted: bool,
+    pub meter: f32,
+    pub max_meter: f32,
+    pub meter_bars: u32,
+    pub stats: StatsSnapshot,
+    pub stat_modifiers: Vec<StatModifierSnapshot>,
+    pub status_effects: Vec<StatusSnapshot>,
+    /// Name of the active stance (None = base stance).
+    pub stance: Option<&'static str>,
+    /// Character-specific gauges (Knight's shield, Zara's mana, ...).
+    pub resources: Vec<ResourceSnapshot>,
+    /// Recently landed moves and how stale each is (for training mode).
+    pub stale_moves: Vec<StaleMoveSnapshot>,
+    /// The combo this fighter is taking, once it's past a single hit.
+    pub combo: Option<ComboSnapshot>,
     pub facing: &'static str,
     pub state: String,
     pub state_num: u8,
     pub attack: Option<AttackSnapshot>,
     pub round_wins: u32,
     pub grounded: bool,
+    /// Frames left frozen in hitstop (renderers should pause animation while > 0).
+    pub hitstop: u32,
+    /// The current attack's invincibility frames are active.
+    pub invulnerable: bool,
+    /// The current attack's armor can soak a hit right now.
+    pub armored: bool,
     pub anim_dir: &'static str,
     pub current_anim: String,
 }
 
+/// Effective stats after buffs and debuffs.
+#[derive(Serialize)]
+pub struct StatsSnapshot {
+    pub attack: f32,
+    pub defense: f32,
+    pub speed: f32,
+    pub knockback_resistance: f32,
+}
+
+/// An active buff (positive amount) or debuff (negative amount).
+#[derive(Serialize)]
+pub struct StatModifierSnapshot {
+    pub stat: String,
+    pub amount: f32,
+    pub frames: u32,
+    pub source: &'static str,
+}
+
+/// An active status effect, for drawing icons and particles.
+#[derive(Serialize)]
+pub struct StatusSnapshot {
+    pub kind: String,
+    pub remaining: u32,
+    pub stacks: u32,
+    pub potency: f32,
+}
+
+/// A character-specific gauge.
+#[derive(Serialize)]
+pub struct ResourceSnapshot {
+    pub name: &'static str,
+    pub value: f32,
+    pub max: f32,
+}
+
+/// A move in the stale queue and the damage multiplier it currently has.
+#[derive(Serialize)]
+pub struct StaleMoveSnapshot {
+    pub name: &'static str,
+    pub multiplier: f32,
+}
+
+/// A combo in progress.
+#[derive(Serialize)]
+pub struct ComboSnapshot {
+    pub hits: u32,
+    pub damage: f32,
+    pub scaling: f32,
+    pub start_frame: u32,
+}
+
+/// A weapon lying on the stage.
+#[derive(Serialize)]
+pub struct ItemSnapshot {
+    pub weapon_type: String,
+    pub position: Vec3Snapshot,
+    pub grounded: bool,
+}
+
 #[derive(Serialize)]
 pub struct ArenaBounds {
     pub min_x: f32,
@@ -50,15 +135,24 @@ pub struct ArenaBounds {
     pub max_z: f32,
 }
 
+#[derive(Serialize)]
+pub struct RoundResultSnapshot {
+    pub winner: Option<u8>,
+    pub reason: &'static str,
+}
+
 #[derive(Serialize)]
 pub struct GameSnapshot {
     pub phase: &'static str,
     pub frame: u32,
-    pub round_timer: f32,
+    pub round_timer: Option<f32>, // None when rounds are untimed
     pub current_round: u32,
     pub countdown_display: &'static str,
     pub last_hit_info: Option<String>,
     pub winner: Option<u8>,
+    pub round_result: Option<RoundResultSnapshot>,
+    pub events: Vec<String>,
+    pub items: Vec<ItemSnapshot>,
     pub fighters: [FighterSnapshot; 2],
 }
 
@@ -77,6 +171,9 @@ pub struct AnimationSetSnapshot {
     pub getting_up: &'static str,
     pub death: &'static str,
     pub sweep_fall: &'static str,
+    pub backdash: &'static str,
+    pub sidestep_left: &'static str,
+    pub sidestep_right: &'static str,
     pub light_attack: &'static str,
     pub heavy_attack: &'static str,
     pub special_attack: &'static str,
@@ -85,9 +182,24 @@ pub struct AnimationSetSnapshot {
     pub aerial: &'static str,
     pub combo_finisher: &'static str,
     pub super_attack: &'static str,
+    pub stances: Vec<StanceAnimSnapshot>,
+}
+
+/// Clips used by one stance: its idle pose, transitions and stance-only moves.
+#[derive(Serialize)]
+pub struct StanceAnimSnapshot {
+    pub name: &'static str,
+    pub idle: &'static str,
+    pub enter: &'static str,
+    pub exit: &'static str,
+    pub moves: Vec<&'static str>,
 }
 
-pub fn animation_set_snapshot(anims: &AnimationSet, moveset: &MoveSet) -> AnimationSetSnapshot {
+pub fn animation_set_snapshot(
+    anims: &AnimationSet,
+    moveset: &MoveSet,
+    stances: &[Stance],
+) -> AnimationSetSnapshot {
     AnimationSetSnapshot {
         dir: anims.dir,
         idle: anims.idle,
@@ -101,6 +213,9 @@ pub fn animation_set_snapshot(anims: &AnimationSet, moveset: &MoveSet) -> Animat
         getting_up: anims.getting_up,
         death: anims.death,
         sweep_fall: anims.sweep_fall,
+        backdash: anims.backdash,
+        sidestep_left: anims.sidestep_left,
+        sidestep_right: anims.sidestep_right,
         light_attack: moveset.light_attack.anim,
         heavy_attack: moveset.heavy_attack.anim,
         special_attack: moveset.special_attack.anim,
@@ -109,6 +224,16 @@ pub fn animation_set_snapshot(anims: &AnimationSet, moveset: &MoveSet) -> Animat
         aerial: moveset.aerial.anim,
         combo_finisher: moveset.combo_finisher.anim,
         super_attack: moveset.super_attack.anim,
+        stances: stances
+            .iter()
+            .map(|s| StanceAnimSnapshot {
+                name: s.name,
+                idle: s.idle_anim,
+                enter: s.enter_anim,
+                exit: s.exit_anim,
+                moves: s.moves.iter().map(|m| m.attack.anim).collect(),
+            })
+            .collect(),
     }
 }
 
@@ -145,6 +270,21 @@ fn state_num(state: FighterState) -> u8 {
         FighterState::Airborne => 6,
         FighterState::Knockdown => 7,
         FighterState::GettingUp => 8,
+        FighterState::Falling => 9,
+        FighterState::TechRoll => 10,
+        FighterState::StanceChange => 11,
+        FighterState::Swept => 12,
+    }
+}
+
+fn round_result_snap(r: RoundResult) -> RoundResultSnapshot {
+    RoundResultSnapshot {
+        winner: r.winner.map(|w| w as u8),
+        reason: match r.reason {
+            RoundEndReason::Knockout => "Knockout",
+            RoundEndReason::TimeUp => "TimeUp",
+            RoundEndReason::RingOut => "RingOut",
+        },
     }
 }
 
@@ -158,6 +298,8 @@ fn attack_type_str(a: ActiveAttack) -> &'static str {
         ActiveAttack::Aerial => "Aerial",
         ActiveAttack::ComboFinisher => "ComboFinisher",
         ActiveAttack::Super => "Super",
+        ActiveAttack::EnhancedSpecial => "EnhancedSpecial",
+        ActiveAttack::Command(_) => "Command",
     }
 }
 
@@ -185,24 +327,25 @@ fn facing_str(f: wv_core::types::Facing) -> &'static str {
 }
 
 fn fighter_snap(f: &wv_core::game::Fighter) -> FighterSnapshot {
-    let attack = f
-        .state_machine
-        .attack
-        .map(|a| {
-            let phase = f.state_machine.attack_phase.unwrap_or(AttackPhase::Startup);
-            AttackSnapshot {
-                attack_type: attack_type_str(a),
-                phase: attack_phase_str(phase),
-                phase_num: attack_phase_num(phase),
-                hit_connected: f.state_machine.hit_connected,
-                frame_counter: f.state_machine.frame_counter,
-                total_frames: f.state_machine.total_frames,
-            }
-        });
+    let attack = f.state_machine.attack.map(|a| {
+        let phase = f.state_machine.attack_phase.unwrap_or(AttackPhase::Startup);
+        AttackSnapshot {
+            attack_type: attack_type_str(a),
+            phase: attack_phase_str(phase),
+            phase_num: attack_phase_num(phase),
+            hit_connected: f.state_machine.hit_connected,
+            hit_index: f.state_machine.hits_landed,
+            hit_count: f.get_attack_data(a).hits,
+            frame_counter: f.state_machine.frame_counter,
+            total_frames: f.state_machine.total_frames,
+        }
+    });
+    let stats = f.effective_stats();
 
     FighterSnapshot {
         fighter_id: format!("{}", f.data.id),
         weapon_type: format!("{}", f.weapon.weapon_type),
+        disarmed: f.is_disarmed(),
         position: vec3_snap(f.physics.position),
         velocity: vec3_snap(f.physics.velocity),
         health: f.health,
@@ -211,12 +354,68 @@ fn fighter_snap(f: &wv_core::game::Fighter) -> FighterSnapshot {
         stamina: f.stamina,
         max_stamina: f.data.max_stamina,
         stamina_pct: f.stamina_pct(),
+        exhausted: f.is_exhausted(),
+        meter: f.meter,
+        max_meter: METER_PER_BAR * METER_BARS as f32,
+        meter_bars: f.meter_bars(),
+        stats: StatsSnapshot {
+            attack: stats.attack,
+            defense: stats.defense,
+            speed: stats.speed,
+            knockback_resistance: stats.knockback_resistance,
+        },
+        stat_modifiers: f
+            .modifiers
+            .iter()
+            .map(|m| StatModifierSnapshot {
+                stat: m.stat.to_string(),
+                amount: m.amount,
+                frames: m.frames,
+                source: m.source,
+            })
+            .collect(),
+        status_effects: f
+            .status
+            .iter()
+            .map(|s| StatusSnapshot {
+                kind: s.effect.kind.to_string(),
+                remaining: s.remaining,
+                stacks: s.stacks,
+                potency: s.effect.potency,
+            })
+            .collect(),
+        stance: f.stance().map(|s| s.name),
+        resources: f
+            .behavior
+            .resources()
+            .into_iter()
+            .map(|r| ResourceSnapshot {
+                name: r.name,
+                value: r.value,
+                max: r.max,
+            })
+            .collect(),
+        stale_moves: f
+            .stale
+            .moves()
+            .into_iter()
+            .map(|(name, multiplier)| StaleMoveSnapshot { name, multiplier })
+            .collect(),
+        combo: f.combo.current().map(|c| ComboSnapshot {
+            hits: c.hits,
+            damage: c.damage,
+            scaling: c.scaling,
+            start_frame: c.start_frame,
+        }),
         facing: facing_str(f.facing),
         state: state_str(f.state_machine.state),
         state_num: state_num(f.state_machine.state),
         attack,
         round_wins: f.round_wins,
         grounded: f.physics.grounded,
+        hitstop: f.hitstop,
+        invulnerable: f.is_invulnerable_to(AttackClass::Strike),
+        armored: f.has_armor(),
         anim_dir: f.data.animations.dir,
         current_anim: f.current_animation().to_string(),
     }
@@ -231,6 +430,17 @@ pub fn snapshot(game: &GameState) -> GameSnapshot {
         countdown_display: game.countdown_display(),
         last_hit_info: game.last_hit_info.clone(),
         winner: game.winner().map(|w| w as u8),
+        round_result: game.round_result.map(round_result_snap),
+        events: game.events.iter().map(|e| e.to_string()).collect(),
+        items: game
+            .items
+            .iter()
+            .map(|item| ItemSnapshot {
+                weapon_type: format!("{}", item.weapon_type),
+                position: vec3_snap(item.physics.position),
+                grounded: item.physics.grounded,
+            })
+            .collect(),
         fighters: [
             fighter_snap(&game.fighters[0]),
             fighter_snap(&game.fighters[1]),
@@ -99,7 +99,11 @@ const STATE_COLORS = {
   HitStun: 0xff0000,
   Airborne: 0xffff44,
   Knockdown: 0x884400,
+  Swept: 0x995522,
   "Getting Up": 0x886644,
+  Falling: 0x666666,
+  "Tech Roll": 0xaaffee,
+  "Stance Change": 0xcc88ff,
 };
 
 // ---------- Keyboard ----------
@@ -118,6 +122,8 @@ function readInputs() {
   const p1_spec  = !!keys["KeyL"];
   const p1_block = !!keys["ShiftLeft"];
   const p1_dash  = !!keys["Space"];
+  const p1_pick  = !!keys["KeyE"];
+  const p1_stnc  = !!keys["KeyQ"];
 
   // P2: Arrows + Numpad 1/2/3/0/Enter
   const p2_fwd   = !!keys["ArrowRight"];
@@ -129,6 +135,8 @@ function readInputs() {
   const p2_spec  = !!keys["Numpad3"];
   const p2_block = !!keys["Numpad0"];
   const p2_dash  = !!keys["NumpadEnter"];
+  const p2_pick  = !!keys["Numpad4"];
+  const p2_stnc  = !!keys["Numpad5"];
 
   // Pack into u32 bitflags
   let bits = 0;
@@ -152,9 +160,34 @@ function readInputs() {
   if (p2_block) bits |= (1 << 16);
   if (p2_dash)  bits |= (1 << 17);
 
+  if (p1_pick)  bits |= (1 << 24);
+  if (p2_pick)  bits |= (1 << 25);
+  if (p1_stnc)  bits |= (1 << 26);
+  if (p2_stnc)  bits |= (1 << 27);
+
   return bits;
 }
 
+// ---------- Status effect icons ----------
+const STATUS_ICONS = { Burn: "\u{1F525}", Stagger: "\u{1F4AB}", Stun: "\u2B50" };
+
+// ---------- Character gauges ----------
+function resourceText(f) {
+  return f.resources.map((r) => ` ${r.name} ${Math.round((r.value / r.max) * 100)}%`).join("");
+}
+
+// Combo the given defender is taking, shown on the attacker's side
+function comboText(defender) {
+  const c = defender.combo;
+  return c ? `  ${c.hits} HITS ${c.damage.toFixed(1)} dmg` : "";
+}
+
+function statusIcons(f) {
+  return f.status_effects
+    .map((s) => " " + (STATUS_ICONS[s.kind] || s.kind) + (s.stacks > 1 ? `x${s.stacks}` : ""))
+    .join("");
+}
+
 // ---------- HUD elements ----------
 const el = {
   p1Name: document.getElementById("p1-name"),
@@ -178,25 +211,26 @@ function updateHUD(snap) {
   const f1 = snap.fighters[0];
   const f2 = snap.fighters[1];
 
-  el.p1Name.textContent = `P1 ${f1.fighter_id} (${f1.weapon_type})`;
+  el.p1Name.textContent = `P1 ${f1.fighter_id} (${f1.weapon_type}) ${"\u25AE".repeat(f1.meter_bars)}${statusIcons(f1)}${resourceText(f1)}${comboText(f2)}`;
   el.p1Health.style.width = `${f1.health_pct * 100}%`;
   el.p1Stamina.style.width = `${f1.stamina_pct * 100}%`;
   el.p1Wins.textContent = "\u2605".repeat(f1.round_wins);
 
-  el.p2Name.textContent = `P2 ${f2.fighter_id} (${f2.weapon_type})`;
+  el.p2Name.textContent = `P2 ${f2.fighter_id} (${f2.weapon_type}) ${"\u25AE".repeat(f2.meter_bars)}${statusIcons(f2)}${resourceText(f2)}${comboText(f1)}`;
   el.p2Health.style.width = `${f2.health_pct * 100}%`;
   el.p2Stamina.style.width = `${f2.stamina_pct * 100}%`;
   el.p2Wins.textContent = "\u2605".repeat(f2.round_wins);
 
   el.round.textContent = `Round ${snap.current_round}`;
-  el.timer.textContent = snap.round_timer.toFixed(1);
+  el.timer.textContent = snap.round_timer === null ? "\u221E" : snap.round_timer.toFixed(1);
 
   // Phase overlay
   if (snap.phase === "Countdown") {
     el.phase.textContent = snap.countdown_display;
     el.phase.style.display = "block";
   } else if (snap.phase === "RoundOver") {
-    el.phase.textContent = "ROUND OVER";
+    el.phase.textContent =
+      snap.round_result && snap.round_result.reason === "RingOut" ? "RING OUT!" : "ROUND OVER";
     el.phase.style.display = "block";
   } else if (snap.phase === "MatchOver") {
     const winnerIdx = snap.winner;
@@ -259,7 +293,7 @@ function updateFighterMesh(mesh, fighter) {
     body.scale.set(1.1, 1.05, 1.1);
   } else if (fighter.state === "HitStun") {
     body.scale.set(0.9, 1.1, 0.9);
-  } else if (fighter.state === "Knockdown") {
+  } else if (fighter.state === "Knockdown" || fighter.state === "Swept") {
     body.scale.set(1.2, 0.5, 1);
   } else {
     body.scale.set(1, 1, 1);